@@ -0,0 +1,235 @@
+// Copyright (C) 2023 Michael Lee <imichael2e2@proton.me/...@gmail.com>
+//
+// Licensed under the MIT License <LICENSE-MIT or
+// https://opensource.org/license/mit> or the GNU General Public License,
+// Version 3.0 or any later version <LICENSE-GPL or
+// https://www.gnu.org/licenses/gpl-3.0.txt>, at your option.
+//
+// This file may not be copied, modified, or distributed except except in
+// compliance with either of the licenses.
+//
+
+use std::io::{Error, ErrorKind, Write};
+
+#[derive(Debug, PartialEq)]
+enum ValueState {
+    Head,
+    Body,
+    Escape,
+    Tail,
+}
+
+///
+/// A writer that takes a WebDriver response body of the form
+/// `{"value":"<base64>"}`, and writes decoded bytes to the inner writer.
+///
+/// The base64 string is decoded as it comes, so the response body does not
+/// need to be buffered. [`B64ValueWriter::finish`] must be called once all
+/// data has been written.
+pub(crate) struct B64ValueWriter<W: Write> {
+    inner: W,
+    state: ValueState,
+    seen_colon: bool,
+    quad: [u8; 4],
+    nquad: usize,
+    npad: usize,
+}
+
+impl<W: Write> B64ValueWriter<W> {
+    pub(crate) fn new(inner: W) -> Self {
+        B64ValueWriter {
+            inner,
+            state: ValueState::Head,
+            seen_colon: false,
+            quad: [0u8; 4],
+            nquad: 0,
+            npad: 0,
+        }
+    }
+
+    ///
+    /// Flush the last partial quantum, and check the value is complete.
+    pub(crate) fn finish(mut self) -> Result<W, Error> {
+        if self.state != ValueState::Tail {
+            return Err(invalid_data("incomplete value"));
+        }
+
+        match self.nquad {
+            0 => {}
+            1 => return Err(invalid_data("dangling base64 character")),
+            n => {
+                let out = decode_quad(&self.quad, n);
+                self.inner.write_all(&out[..n - 1])?;
+            }
+        }
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    fn feed(&mut self, c: u8, out: &mut Vec<u8>) -> Result<(), Error> {
+        if c == b'=' {
+            self.npad += 1;
+            return Ok(());
+        }
+        if self.npad > 0 {
+            return Err(invalid_data("base64 character after padding"));
+        }
+        let sextet = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'\r' | b'\n' | b' ' => return Ok(()),
+            _ => return Err(invalid_data("invalid base64 character")),
+        };
+        self.quad[self.nquad] = sextet;
+        self.nquad += 1;
+        if self.nquad == 4 {
+            out.extend(decode_quad(&self.quad, 4));
+            self.nquad = 0;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for B64ValueWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Error> {
+        let mut out = Vec::<u8>::with_capacity(buf.len() / 4 * 3 + 3);
+
+        for &c in buf {
+            match self.state {
+                ValueState::Head => {
+                    if c == b':' {
+                        self.seen_colon = true;
+                    } else if c == b'"' && self.seen_colon {
+                        self.state = ValueState::Body;
+                    }
+                }
+                ValueState::Body => match c {
+                    b'"' => self.state = ValueState::Tail,
+                    b'\\' => self.state = ValueState::Escape,
+                    _ => self.feed(c, &mut out)?,
+                },
+                ValueState::Escape => {
+                    // only "\/" is meaningful inside base64 text
+                    self.state = ValueState::Body;
+                    self.feed(c, &mut out)?;
+                }
+                ValueState::Tail => {}
+            }
+        }
+
+        self.inner.write_all(&out)?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        self.inner.flush()
+    }
+}
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+///
+/// Encode `data` as standard base64 with padding.
+#[cfg_attr(not(feature = "firefox"), allow(dead_code))]
+pub(crate) fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let mut v = 0u32;
+        for (i, b) in chunk.iter().enumerate() {
+            v |= (*b as u32) << (16 - 8 * i);
+        }
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[((v >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+
+    out
+}
+
+fn decode_quad(quad: &[u8; 4], n: usize) -> [u8; 3] {
+    let mut v = 0u32;
+    for (i, s) in quad.iter().enumerate() {
+        if i < n {
+            v |= (*s as u32) << (18 - 6 * i);
+        }
+    }
+
+    [(v >> 16) as u8, (v >> 8) as u8, v as u8]
+}
+
+fn invalid_data(msg: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod utst {
+    use super::*;
+
+    fn decode_value(body: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut w = B64ValueWriter::new(Vec::<u8>::with_capacity(body.len() / 4 * 3));
+        w.write_all(body)?;
+
+        w.finish()
+    }
+
+    #[test]
+    fn _1() {
+        assert_eq!(decode_value(br#"{"value":""}"#).unwrap(), b"");
+        assert_eq!(decode_value(br#"{"value":"Zg=="}"#).unwrap(), b"f");
+        assert_eq!(decode_value(br#"{"value":"Zm8="}"#).unwrap(), b"fo");
+        assert_eq!(decode_value(br#"{"value":"Zm9v"}"#).unwrap(), b"foo");
+        assert_eq!(decode_value(br#"{"value":"Zm9vYg"}"#).unwrap(), b"foob");
+        assert_eq!(
+            decode_value(br#"{ "value" : "Zm9vYmFy" }"#).unwrap(),
+            b"foobar"
+        );
+        assert_eq!(decode_value(br#"{"value":"\/w=="}"#).unwrap(), [0xff]);
+    }
+
+    #[test]
+    fn _2() {
+        // fed byte by byte, as if from a slow stream
+        let body = br#"{"value":"iVBORw0KGgo="}"#;
+        let mut w = B64ValueWriter::new(vec![]);
+        for b in body.chunks(1) {
+            w.write_all(b).unwrap();
+        }
+        assert_eq!(
+            w.finish().unwrap(),
+            [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n']
+        );
+    }
+
+    #[test]
+    fn _3() {
+        assert!(decode_value(br#"{"value":"Zm9v"#).is_err());
+        assert!(decode_value(br#"{"value":"Z"}"#).is_err());
+        assert!(decode_value(br#"{"value":"Zm=v"}"#).is_err());
+        assert!(decode_value(br#"{"value":"Zm9v!"}"#).is_err());
+    }
+
+    #[test]
+    fn _4() {
+        assert_eq!(encode(b""), "");
+        assert_eq!(encode(b"f"), "Zg==");
+        assert_eq!(encode(b"fo"), "Zm8=");
+        assert_eq!(encode(b"foo"), "Zm9v");
+        assert_eq!(encode(b"foobar"), "Zm9vYmFy");
+        assert_eq!(encode(&[0xff, 0xfe]), "//4=");
+
+        let data: Vec<u8> = (0..=255).collect();
+        let body = format!(r#"{{"value":"{}"}}"#, encode(&data));
+        assert_eq!(decode_value(body.as_bytes()).unwrap(), data);
+    }
+}
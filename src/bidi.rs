@@ -111,11 +111,12 @@ where
     D: CreateBidiClient + for<'de, 'c1, 'c2> CreateW3cSession<'de, 'c1, 'c2>,
 {
     fn drop(&mut self) {
-        for i in 0..self.ssmetas.len() {
-            let ssmeta = &self.ssmetas[i];
-            self.del_session(&ssmeta.ssid)
-                .expect("delete driver session");
-            self.ssmetas.remove(i);
+        let ssmetas = std::mem::take(&mut self.ssmetas);
+        for ssmeta in ssmetas.iter() {
+            // the driver may be gone already, nothing more can be done then
+            if let Err(_e) = self.del_session(&ssmeta.ssid) {
+                dbgg!(_e);
+            }
         }
     }
 }
@@ -190,9 +191,8 @@ where
                 req.http1p1()
                     .get("/status")
                     .host(&self.raddr())
-                    .send_through(&mut stream)
-                    .unwrap();
-                let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0).unwrap();
+                    .send_through(&mut stream)?;
+                let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0)?;
 
                 dbgg!(String::from_utf8_lossy(resp.msgbody()));
 
@@ -210,7 +210,7 @@ where
                         _ => Err(WdcError::Buggy),
                     }
                 } else {
-                    check_fail_drvcmd(resp.msgbody())?;
+                    check_fail_drvcmd(&resp)?;
                     Err(WdcError::Buggy) // unreachable
                 }
             }
@@ -228,20 +228,18 @@ where
 
         let mut wsmsg = WebSocketMessage::new();
         wsmsg.allow_small().allow_medium().allow_large();
-        wsmsg
-            .set_message_data(
-                if ctx_type == 1 {
-                    br#"{"id":123,"method":"browsingContext.create","params":{"type":"tab"}}"#
-                } else {
-                    br#"{"id":123,"method":"browsingContext.create","params":{"type":"window"}}"#
-                },
-                vec![WspSett::TextMsg, WspSett::Mask],
-            )
-            .unwrap();
-        wsmsg.send_through(&mut stream).unwrap();
-
-        let resp = WebSocketMessage::from_stream(&mut stream).unwrap();
-        let respdata = resp.get_message_data().unwrap();
+        wsmsg.set_message_data(
+            if ctx_type == 1 {
+                br#"{"id":123,"method":"browsingContext.create","params":{"type":"tab"}}"#
+            } else {
+                br#"{"id":123,"method":"browsingContext.create","params":{"type":"window"}}"#
+            },
+            vec![WspSett::TextMsg, WspSett::Mask],
+        )?;
+        wsmsg.send_through(&mut stream)?;
+
+        let resp = WebSocketMessage::from_stream(&mut stream)?;
+        let respdata = resp.get_message_data()?;
 
         dbgg!(String::from_utf8_lossy(&respdata));
 
@@ -256,7 +254,8 @@ where
             context: String,
         }
 
-        let cmdresp = serde_json::from_slice::<CommandResponse>(&respdata).unwrap();
+        let cmdresp =
+            serde_json::from_slice::<CommandResponse>(&respdata).map_err(|_| WdcError::Buggy)?;
 
         self.ctxlist.push(cmdresp.result.context);
         // self.ctxlist
@@ -274,20 +273,20 @@ where
         let mut stream = self.ws_stream.as_ref().unwrap().lock().unwrap();
 
         // {"id":123,"method":"browsingContext.navigate","params":{"url":"https://www.w3.org","context":"67BAB34FF3FD05FF8366DAD6A34E181D"}}
-        let mb_string = format!(
-            r#"{{"id":123,"method":"browsingContext.navigate","params":{{"url":"{}","context":"{}"}}}}"#,
-            url, ctx_id
-        );
+        let mb_string = serde_json::json!({
+            "id": 123,
+            "method": "browsingContext.navigate",
+            "params": { "url": url, "context": ctx_id },
+        })
+        .to_string();
         dbgg!(&mb_string);
         let mut wsmsg = WebSocketMessage::new();
         wsmsg.allow_small().allow_medium().allow_large();
-        wsmsg
-            .set_message_data(mb_string.as_bytes(), vec![WspSett::TextMsg, WspSett::Mask])
-            .unwrap();
-        wsmsg.send_through(&mut stream).unwrap();
+        wsmsg.set_message_data(mb_string.as_bytes(), vec![WspSett::TextMsg, WspSett::Mask])?;
+        wsmsg.send_through(&mut stream)?;
 
-        let resp = WebSocketMessage::from_stream(&mut stream).unwrap();
-        let respdata = resp.get_message_data().unwrap();
+        let resp = WebSocketMessage::from_stream(&mut stream)?;
+        let respdata = resp.get_message_data()?;
         dbgg!(String::from_utf8_lossy(&respdata));
 
         // check_fail_drvcmd(&respdata)?;
@@ -328,13 +327,11 @@ where
 
         let mut wsmsg = WebSocketMessage::new();
         wsmsg.allow_small().allow_medium().allow_large();
-        wsmsg
-            .set_message_data(mb_string.as_bytes(), vec![WspSett::TextMsg, WspSett::Mask])
-            .unwrap();
-        wsmsg.send_through(&mut stream).unwrap();
+        wsmsg.set_message_data(mb_string.as_bytes(), vec![WspSett::TextMsg, WspSett::Mask])?;
+        wsmsg.send_through(&mut stream)?;
 
-        let resp = WebSocketMessage::from_stream(&mut stream).unwrap();
-        let respdata = resp.get_message_data().unwrap();
+        let resp = WebSocketMessage::from_stream(&mut stream)?;
+        let respdata = resp.get_message_data()?;
         dbgg!(String::from_utf8_lossy(&respdata));
 
         // check_fail_drvcmd(&respdata)?;
@@ -383,6 +380,7 @@ where
             browser_version,
             platform_name,
             browser_profile,
+            ..Default::default()
         });
     }
 
@@ -393,7 +391,7 @@ where
     fn ensure_remote_connected(&mut self) -> Result<(), WdcError> {
         match TcpStream::connect(self.raddr()) {
             Ok(stream) => {
-                stream.set_nodelay(true).unwrap();
+                stream.set_nodelay(true)?;
                 self.http_stream = Some(Arc::new(Mutex::new(stream)));
             }
             Err(_err) => {}
@@ -416,10 +414,9 @@ where
         req.http1p1()
             .delete(&format!("/session/{}", ssid))
             .host(&self.raddr())
-            .send_through(&mut stream)
-            .unwrap();
+            .send_through(&mut stream)?;
 
-        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0).unwrap();
+        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0)?;
 
         dbgg!(String::from_utf8_lossy(resp.headers()));
         dbgg!(String::from_utf8_lossy(resp.msgbody()));
@@ -462,10 +459,9 @@ where
             .host(&self.raddr())
             .msgbody_from_slice(&mb)
             .content_type("application/json")
-            .send_through(&mut stream)
-            .unwrap();
+            .send_through(&mut stream)?;
 
-        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0).unwrap();
+        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0)?;
 
         dbgg!(String::from_utf8_lossy(resp.msgbody()));
 
@@ -482,15 +478,17 @@ where
 
                     // FIXME: maybe RE is overkill?
                     let re = regex::Regex::new(r"ws://(.*)/session/(.*)").unwrap();
-                    let cap = re.captures(sess.wsurl().unwrap()).unwrap();
+                    let cap = sess
+                        .wsurl()
+                        .and_then(|v| re.captures(v))
+                        .ok_or_else(|| WdcError::BadWebSocket("invalid WebSocket URL".into()))?;
                     let raddr = cap.get(1).unwrap().as_str();
                     let newssid = cap.get(2).unwrap().as_str();
                     let ws_uri = format!("/session/{}", newssid);
                     dbgg!(raddr, newssid);
 
                     if let Ok(mut wsstream) = TcpStream::connect(&raddr) {
-                        WebSocketHandshaker::try_as_client(&mut wsstream, &ws_uri, &raddr)
-                            .expect("handshake");
+                        WebSocketHandshaker::try_as_client(&mut wsstream, &ws_uri, raddr)?;
                         self.ws_stream = Some(Arc::new(Mutex::new(wsstream)));
                         dbgmsg!("yes!");
                     }
@@ -500,7 +498,7 @@ where
                 _ => Err(WdcError::Buggy),
             }
         } else {
-            check_fail_drvcmd(resp.msgbody())?;
+            check_fail_drvcmd(&resp)?;
             Err(WdcError::Buggy) // unreachable
         }
     }
@@ -11,6 +11,13 @@
 
 use std::sync::Arc;
 
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::Serialize;
+
+use crate::wdcmd::goog::{ExecCdpParam, ExecCdpResult, GetLogParam, NetworkConditions};
+use crate::wdcmd::goog::{GetLogResult, GetLogResultGetter, LogEntry, SetNetworkConditionsParam};
+use crate::wdcmd::goog::{GetLogTypesResult, GetLogTypesResultGetter};
+use crate::wdcmd::goog::{GetNetworkConditionsResult, GetNetworkConditionsResultGetter};
 use crate::wdcmd::session::{ChromCapRequ, ChromSessResult, ChromiumCapa};
 use crate::wdcmd::session::{ChromCapRequSetter, ChromiumCapaGetter};
 use crate::wdcmd::session::{W3cCapaGetter, W3cSessResultGetter};
@@ -19,8 +26,10 @@ use crate::CreateW3cSession;
 use crate::CreateWebDrvClient;
 use crate::WdcError;
 
+use crate::genericdrv::HttpMethod;
 use crate::genericdrv::RendVendor;
 use crate::genericdrv::WebDrvClient;
+use crate::genericdrv::NO_BODY;
 
 use crate::httpp::HttpRequestParts;
 use crate::httpp::HttpResponseParts;
@@ -44,6 +53,8 @@ impl CreateWebDrvClient for ChromeDriver {
             rport,
             rstream: None,
             ssmetas: vec![],
+            cur_ssidx: 0,
+            detach_on_drop: false,
         }
     }
 
@@ -92,10 +103,9 @@ impl WebDrvClient<ChromeDriver> {
             .host(&self.raddr())
             .msgbody_from_slice(&mb)
             .content_type("application/json")
-            .send_through(&mut stream)
-            .unwrap();
+            .send_through(&mut stream)?;
 
-        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0).unwrap();
+        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0)?;
 
         dbgg!(String::from_utf8_lossy(resp.msgbody()));
 
@@ -114,18 +124,142 @@ impl WebDrvClient<ChromeDriver> {
                         Some(sess.browser_version().to_string()),
                         Some(sess.platform_name().to_string()),
                         None,
-                    );
+                    )
+                    .fill_w3c_capa(&sess);
                     Ok(())
                 }
                 _ => Err(WdcError::Buggy),
             }
         } else {
-            check_fail_drvcmd(resp.msgbody())?;
+            check_fail_drvcmd(&resp)?;
             Err(WdcError::Buggy) // unreachable
         }
     }
 }
 
+///
+/// ChromeDriver-specific commands.
+impl WebDrvClient<ChromeDriver> {
+    ///
+    /// Execute the Chrome DevTools Protocol command `method`, and return its
+    /// result.
+    ///
+    /// `params` is anything serialized as a map, e.g. a struct or a
+    /// [`serde_json::Value`] object, or `()` for no parameters. Other values
+    /// fail with [`WdcError::InvalidArgument`] before anything is sent.
+    ///
+    /// The result is deserialized into `T`, use [`serde_json::Value`] if it
+    /// is not known beforehand.
+    pub fn execute_cdp<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: impl Serialize,
+    ) -> Result<T, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let params = match serde_json::to_value(params) {
+            Ok(serde_json::Value::Null) => serde_json::Map::new(),
+            Ok(serde_json::Value::Object(m)) => m,
+            Ok(_) => {
+                return Err(WdcError::InvalidArgument(
+                    "cdp parameters must be a map".to_string(),
+                ))
+            }
+            Err(e) => return Err(WdcError::InvalidArgument(e.to_string())),
+        };
+
+        let res: ExecCdpResult<serde_json::Value> = self.send_cmd(
+            HttpMethod::Post,
+            &format!("/session/{}/goog/cdp/execute", ssid),
+            Some(&ExecCdpParam {
+                cmd: method,
+                params,
+            }),
+        )?;
+
+        serde_json::from_value(res.value).map_err(|e| WdcError::UnexpectedCdpResult(e.to_string()))
+    }
+
+    ///
+    /// Get the network conditions being emulated.
+    ///
+    /// It fails with
+    /// [`WebDriverErrorCode::UnknownError`](crate::wdcmd::err::WebDriverErrorCode::UnknownError)
+    /// if none is set.
+    pub fn network_conditions(&self) -> Result<NetworkConditions, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: GetNetworkConditionsResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!("/session/{}/chromium/network_conditions", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(res.network_conditions().clone())
+    }
+
+    ///
+    /// Emulate network conditions `conds`.
+    pub fn set_network_conditions(&self, conds: &NetworkConditions) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/chromium/network_conditions", ssid),
+            Some(&SetNetworkConditionsParam {
+                network_conditions: conds,
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    ///
+    /// Stop emulating network conditions.
+    pub fn delete_network_conditions(&self) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Delete,
+            &format!("/session/{}/chromium/network_conditions", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(())
+    }
+
+    ///
+    /// Get the types of log available, e.g. "browser" and "driver".
+    pub fn log_types(&self) -> Result<Vec<String>, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: GetLogTypesResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!("/session/{}/se/log/types", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(res.log_types().to_vec())
+    }
+
+    ///
+    /// Get entries of log `log_type` collected since last retrieval.
+    ///
+    /// Which entries are collected is decided by "goog:loggingPrefs"
+    /// capability.
+    pub fn log(&self, log_type: &str) -> Result<Vec<LogEntry>, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: GetLogResult = self.send_cmd(
+            HttpMethod::Post,
+            &format!("/session/{}/se/log", ssid),
+            Some(&GetLogParam { log_type }),
+        )?;
+
+        Ok(res.entries().to_vec())
+    }
+}
+
 ///
 /// Initialize a WebDriver client instance.
 ///
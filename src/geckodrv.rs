@@ -9,8 +9,15 @@
 // compliance with either of the licenses.
 //
 
+use std::io::Write;
+use std::path::Path;
 use std::sync::Arc;
 
+use serde::de::IgnoredAny;
+
+use crate::wdcmd::moz::{GeckoContext, GetContextResult, GetContextResultGetter};
+use crate::wdcmd::moz::{InstallAddonParam, InstallAddonResult, InstallAddonResultGetter};
+use crate::wdcmd::moz::{SetContextParam, UninstallAddonParam};
 use crate::wdcmd::session::{FirefoxCapa, GeckoCapRequ, GeckoSessResult};
 use crate::wdcmd::session::{FirefoxCapaGetter, GeckoCapRequSetter};
 use crate::wdcmd::session::{W3cCapaGetter, W3cSessResultGetter};
@@ -19,14 +26,20 @@ use crate::CreateW3cSession;
 use crate::CreateWebDrvClient;
 use crate::WdcError;
 
+use crate::genericdrv::HttpMethod;
 use crate::genericdrv::RendVendor;
 use crate::genericdrv::WebDrvClient;
+use crate::genericdrv::NO_BODY;
 
 use crate::httpp::HttpRequestParts;
 use crate::httpp::HttpResponseParts;
 
 use crate::genericdrv::check_fail_drvcmd;
 
+use crate::b64;
+use crate::httpp;
+use crate::zip;
+
 // GeckoDriver //
 
 /// A placeholder for Mozilla GeckoDriver.
@@ -45,6 +58,8 @@ impl CreateWebDrvClient for GeckoDriver {
             rport,
             rstream: None,
             ssmetas: vec![],
+            cur_ssidx: 0,
+            detach_on_drop: false,
         }
     }
 
@@ -135,7 +150,109 @@ pub fn init_singl_ff(
     }
 }
 
+///
+/// GeckoDriver-specific commands.
 impl WebDrvClient<GeckoDriver> {
+    ///
+    /// Get the context subsequent commands run in.
+    pub fn context(&self) -> Result<GeckoContext, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: GetContextResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!("/session/{}/moz/context", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(res.context())
+    }
+
+    ///
+    /// Set the context subsequent commands run in, e.g. switch to
+    /// [`GeckoContext::Chrome`] for privileged scripts.
+    pub fn set_context(&self, context: GeckoContext) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/moz/context", ssid),
+            Some(&SetContextParam { context }),
+        )?;
+
+        Ok(())
+    }
+
+    ///
+    /// Install the add-on at `path`, return its id.
+    ///
+    /// `path` is either an `.xpi` file or an unpacked add-on directory, which
+    /// is zipped on the fly. Either way it is read locally and sent to
+    /// WebDriver server, so the server does not need to access `path`. A
+    /// `temporary` add-on is removed once the browser exits, and need not be
+    /// signed. Failing to read `path` is reported as [`WdcError::Io`].
+    pub fn install_addon(&self, path: &str, temporary: bool) -> Result<String, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let xpi = if Path::new(path).is_dir() {
+            zip::zip_dir(Path::new(path))?
+        } else {
+            std::fs::read(path)?
+        };
+
+        let res: InstallAddonResult = self.send_cmd(
+            HttpMethod::Post,
+            &format!("/session/{}/moz/addon/install", ssid),
+            Some(&InstallAddonParam {
+                addon: &b64::encode(&xpi),
+                temporary,
+            }),
+        )?;
+
+        Ok(res.addon_id().to_string())
+    }
+
+    ///
+    /// Uninstall the add-on `id`.
+    pub fn uninstall_addon(&self, id: &str) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/moz/addon/uninstall", ssid),
+            Some(&UninstallAddonParam { id }),
+        )?;
+
+        Ok(())
+    }
+
+    ///
+    /// Take a screenshot of the whole page, rather than the viewport only,
+    /// and save it to `save_path` as PNG image.
+    pub fn screenshot_full(&self, save_path: &str) -> Result<(), WdcError> {
+        let mut pbody_file = httpp::create_pbody_file(save_path)?;
+
+        self.screenshot_full_to_writer(&mut pbody_file)
+    }
+
+    ///
+    /// Take a screenshot of the whole page, as PNG image.
+    pub fn screenshot_full_to_vec(&self) -> Result<Vec<u8>, WdcError> {
+        let mut png = Vec::<u8>::new();
+
+        self.screenshot_full_to_writer(&mut png)?;
+
+        Ok(png)
+    }
+
+    ///
+    /// Take a screenshot of the whole page, and write it to `sink` as PNG
+    /// image.
+    pub fn screenshot_full_to_writer(&self, sink: &mut dyn Write) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        self.b64_value_into(&format!("/session/{}/moz/screenshot/full", ssid), sink)
+    }
+
     fn ff_session_singl(
         &mut self,
         capa: &(impl W3cCapaGetter + FirefoxCapaGetter),
@@ -168,10 +285,9 @@ impl WebDrvClient<GeckoDriver> {
             .host(&self.raddr())
             .msgbody_from_slice(&mb)
             .content_type("application/json")
-            .send_through(&mut stream)
-            .unwrap();
+            .send_through(&mut stream)?;
 
-        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0).unwrap();
+        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0)?;
 
         dbgg!(String::from_utf8_lossy(resp.msgbody()));
 
@@ -190,13 +306,14 @@ impl WebDrvClient<GeckoDriver> {
                         Some(sess.browser_version().to_string()),
                         Some(sess.platform_name().to_string()),
                         Some(sess.profile().to_string()),
-                    );
+                    )
+                    .fill_w3c_capa(&sess);
                     Ok(())
                 }
                 _ => Err(WdcError::Buggy),
             }
         } else {
-            check_fail_drvcmd(resp.msgbody())?;
+            check_fail_drvcmd(&resp)?;
             Err(WdcError::Buggy) // unreachable
         }
     }
@@ -9,41 +9,73 @@
 // compliance with either of the licenses.
 //
 
+use std::io::Write;
 use std::net::TcpStream;
+use std::ops::Deref;
 use std::str;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use serde::de::{DeserializeOwned, IgnoredAny};
 use serde::Deserialize;
 use serde::Serialize;
 
-use crate::wdcmd::err::BadCmdResp;
+use crate::wdcmd::alert::{GetAlertTextResult, GetAlertTextResultGetter, SendAlertTextParam};
+use crate::wdcmd::cookie::{
+    AddCookieParam, Cookie, GetCookieResult, GetCookieResultGetter, GetCookiesResult,
+    GetCookiesResultGetter,
+};
+use crate::wdcmd::elem::{
+    ElemRect, ElemSendKeysParam, GetElemAttrResult, GetElemAttrResultGetter, GetElemCssValueResult,
+    GetElemCssValueResultGetter, GetElemPropResult, GetElemPropResultGetter, GetElemRectResult,
+    GetElemRectResultGetter, GetElemTagNameResult, GetElemTagNameResultGetter, GetElemTextResult,
+    GetElemTextResultGetter, IsElemStateResult, IsElemStateResultGetter,
+};
+use crate::wdcmd::err::{BadCmdResp, WebDriverError};
 use crate::wdcmd::find_elem::{
-    FindElemFilter, FindElemFilterSetter, FindElemsResult, FindElemsResultGetter,
+    ElemRef, FindElemFilter, FindElemResult, FindElemResultGetter, FindElemsResult,
+    FindElemsResultGetter, GetShadowRootResult, GetShadowRootResultGetter, Locator,
 };
+use crate::wdcmd::get_url::{
+    GetTitleResult, GetTitleResultGetter, GetUrlResult, GetUrlResultGetter, NaviToParam,
+};
+use crate::wdcmd::script::{ExecScriptParam, ExecScriptResult};
 use crate::wdcmd::status::{DrvStatResult, DrvStatResultGetter};
+use crate::wdcmd::window::{
+    FrameId, GetWindowHandleResult, GetWindowHandleResultGetter, GetWindowHandlesResult,
+    GetWindowHandlesResultGetter, GetWindowRectResult, GetWindowRectResultGetter, NewWindowParam,
+    NewWindowResult, NewWindowResultGetter, SwitchToFrameParam, SwitchToWindowParam, WindowHandle,
+    WindowRect, WindowType,
+};
 
-use crate::wdcmd::session::{W3cCapRequSetter, W3cCapaGetter, W3cCapaSetter, W3cSessResultGetter};
+use crate::wdcmd::timeouts::{GetTimeoutsResult, GetTimeoutsResultGetter};
 
-use crate::wdcmd::actions::ActionGroup;
+use crate::wdcmd::session::{
+    Timeouts, W3cCapRequSetter, W3cCapaGetter, W3cCapaSetter, W3cSessResultGetter,
+};
+
+use crate::wdcmd::actions::{ActionGroup, InputState};
+use crate::wdcmd::print::PrintOptions;
 
+use crate::b64::B64ValueWriter;
 use crate::httpp;
+use crate::strv::StrValueWriter;
 
 use httpp::HttpRequestParts;
 use httpp::HttpResponseParts;
 
 use crate::WdcError;
 
-pub(crate) fn check_fail_drvcmd(s: &[u8]) -> Result<(), WdcError> {
-    match serde_json::from_slice::<BadCmdResp>(s) {
+pub(crate) fn check_fail_drvcmd(resp: &HttpResponseParts) -> Result<(), WdcError> {
+    match serde_json::from_slice::<BadCmdResp>(resp.msgbody()) {
         Ok(eobj) => {
             if eobj.err() == "session not created" && eobj.msg() == "Session is already started" {
                 Err(WdcError::BusyCreateSession)
             } else {
-                Err(WdcError::BadDrvCmd(
-                    eobj.err().to_string(),
-                    eobj.msg().to_string(),
-                ))
+                Err(WdcError::BadDrvCmd(WebDriverError::from_resp(
+                    resp.status_code(),
+                    &eobj,
+                )))
             }
         }
         Err(_e) => {
@@ -53,6 +85,30 @@ pub(crate) fn check_fail_drvcmd(s: &[u8]) -> Result<(), WdcError> {
     }
 }
 
+fn script_args(args: impl Serialize) -> Result<Vec<serde_json::Value>, WdcError> {
+    match serde_json::to_value(args) {
+        Ok(serde_json::Value::Null) => Ok(vec![]),
+        Ok(serde_json::Value::Array(v)) => Ok(v),
+        Ok(_) => Err(WdcError::InvalidArgument(
+            "script arguments must be a sequence".to_string(),
+        )),
+        Err(e) => Err(WdcError::InvalidArgument(e.to_string())),
+    }
+}
+
+/// The placeholder of session id in [`WebDriverCommand::path`].
+const SESSION_ID: &str = "{session id}";
+
+/// No request body, for commands without parameters.
+pub(crate) const NO_BODY: Option<&()> = None;
+
+///
+/// The response of a command, of which only "value" is interesting.
+#[derive(Deserialize)]
+struct CmdResult<T> {
+    value: T,
+}
+
 pub enum RendVendor {
     Mozilla,
     Google,
@@ -80,6 +136,65 @@ where
     fn rend_vendor() -> RendVendor;
 }
 
+///
+/// The HTTP method of a WebDriver command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpMethod {
+    Get,
+    Post,
+    Delete,
+}
+
+///
+/// A WebDriver command, sent by [`WebDrvClient::execute`].
+///
+/// Commands not shipped by this crate, e.g. vendor-specific ones, can be sent
+/// by implementing this trait.
+///
+/// ```ignore
+/// use wdc::{HttpMethod, WebDriverCommand};
+///
+/// struct GetContext;
+///
+/// impl WebDriverCommand for GetContext {
+///     type Body = ();
+///     type Output = String;
+///
+///     fn method(&self) -> HttpMethod {
+///         HttpMethod::Get
+///     }
+///
+///     fn path(&self) -> String {
+///         "/session/{session id}/moz/context".to_string()
+///     }
+/// }
+///
+/// let context = wdc.execute(&GetContext)?;
+/// ```
+pub trait WebDriverCommand {
+    ///
+    /// The request body, serialized as JSON.
+    type Body: Serialize;
+    ///
+    /// The "value" field of a successful response.
+    type Output: DeserializeOwned;
+
+    fn method(&self) -> HttpMethod;
+
+    ///
+    /// The path of the endpoint, `{session id}` in it is replaced by the id of
+    /// current session.
+    fn path(&self) -> String;
+
+    ///
+    /// The request body. A POST command without body sends an empty JSON
+    /// object, a body failing to serialize is rejected with
+    /// [`WdcError::InvalidArgument`].
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
 ///
 /// Create standard-compliance WebDriver sessions.
 pub trait CreateW3cSession<'de, 'c1, 'c2>
@@ -96,13 +211,22 @@ where
 
 ///
 /// The metadata that a WebDriver session has.
-#[derive(Debug)]
+///
+/// It can be serialized, e.g. persisted by one process, and given to
+/// [`WebDrvClient::attach_with_meta`] by another. The state of input sources
+/// is not serialized.
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct SessionMeta {
+    #[serde(rename = "session_id")]
     pub(crate) ssid: String,
     pub(crate) browser_name: Option<String>,
     pub(crate) browser_version: Option<String>,
     pub(crate) platform_name: Option<String>,
     pub(crate) browser_profile: Option<String>,
+    pub(crate) window_rect: Option<bool>,
+    pub(crate) unhandled_prompt_behavior: Option<String>,
+    #[serde(skip)]
+    pub(crate) input_state: Mutex<InputState>,
 }
 
 impl SessionMeta {
@@ -144,6 +268,29 @@ impl SessionMeta {
     pub fn browser_profile(&self) -> Option<&str> {
         self.browser_profile.as_ref().map(|x| x.as_str())
     }
+
+    ///
+    /// Whether the session supports resizing and repositioning windows, as
+    /// the `setWindowRect` capability reported by WebDriver server.
+    #[inline]
+    pub fn window_rect(&self) -> Option<bool> {
+        self.window_rect
+    }
+
+    ///
+    /// How the session handles user prompts that no command deals with, as
+    /// the `unhandledPromptBehavior` capability reported by WebDriver server.
+    /// For example, `dismiss and notify`
+    #[inline]
+    pub fn unhandled_prompt_behavior(&self) -> Option<&str> {
+        self.unhandled_prompt_behavior.as_deref()
+    }
+
+    // fill the standard capabilities that matter to later commands
+    pub(crate) fn fill_w3c_capa(&mut self, sess: &impl W3cSessResultGetter) {
+        self.window_rect = sess.window_rect();
+        self.unhandled_prompt_behavior = sess.unhandled_prompt_behavior().map(|x| x.to_string());
+    }
 }
 
 // WebDrvClient //
@@ -153,7 +300,8 @@ impl SessionMeta {
 ///
 /// It serves as the basic client implementation, with several assumptions:
 ///
-/// 1. There is **one** active session at most.
+/// 1. Commands target the first session, unless another one is picked by a
+///    [`SessionHandle`].
 /// 2. The session is standard-compliant.
 ///
 /// See module-level docs [`crate`] for the usage.
@@ -168,6 +316,8 @@ where
     pub(crate) rport: u16,
     pub(crate) rstream: Option<Arc<Mutex<TcpStream>>>,
     pub(crate) ssmetas: Vec<SessionMeta>,
+    pub(crate) cur_ssidx: usize,
+    pub(crate) detach_on_drop: bool,
 }
 
 impl<D> Drop for WebDrvClient<D>
@@ -175,11 +325,16 @@ where
     D: CreateWebDrvClient + for<'de, 'c1, 'c2> CreateW3cSession<'de, 'c1, 'c2>,
 {
     fn drop(&mut self) {
-        for i in 0..self.ssmetas.len() {
-            let ssmeta = &self.ssmetas[i];
-            self.del_session(&ssmeta.ssid)
-                .expect("delete driver session");
-            self.ssmetas.remove(i);
+        if self.detach_on_drop {
+            return;
+        }
+
+        let ssmetas = std::mem::take(&mut self.ssmetas);
+        for ssmeta in ssmetas.iter() {
+            // the driver may be gone already, nothing more can be done then
+            if let Err(_e) = self.del_session(&ssmeta.ssid) {
+                dbgg!(_e);
+            }
         }
     }
 }
@@ -197,358 +352,1113 @@ impl<D> WebDrvClient<D>
 where
     D: CreateWebDrvClient + for<'de, 'c1, 'c2> CreateW3cSession<'de, 'c1, 'c2>,
 {
-    // commands
-
-    pub fn is_ready(&self) -> Result<(), WdcError> {
-        let mut req = HttpRequestParts::from_scratch();
+    ///
+    /// Attach to the live session `ssid` on WebDriver server, instead of
+    /// creating one.
+    ///
+    /// The session is checked by "Get Timeouts" command, which changes
+    /// nothing. Like any other session of the client, it is deleted once the
+    /// client is dropped, unless [`Self::set_detach_on_drop`].
+    pub fn attach(rhost: &str, rport: u16, ssid: &str) -> Result<Self, WdcError> {
+        Self::attach_with_meta(
+            rhost,
+            rport,
+            SessionMeta {
+                ssid: ssid.to_string(),
+                ..Default::default()
+            },
+        )
+    }
 
-        match &self.rstream {
-            Some(rstream) => {
-                let mut stream = rstream.lock().unwrap(); // -
-                req.http1p1()
-                    .get("/status")
-                    .host(&self.raddr())
-                    .send_through(&mut stream)
-                    .unwrap();
-                let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0).unwrap();
-
-                dbgg!(String::from_utf8_lossy(resp.msgbody()));
-
-                if resp.is_ok() {
-                    match serde_json::from_slice::<DrvStatResult>(resp.msgbody()) {
-                        Ok(res) => {
-                            if res.ready() {
-                                Ok(())
-                            } else if res.msg() == "Session already started" {
-                                Err(WdcError::DriverNotReadyBusySession)
-                            } else {
-                                Err(WdcError::WebDriverNotReady)
-                            }
-                        }
-                        _ => Err(WdcError::Buggy),
-                    }
-                } else {
-                    check_fail_drvcmd(resp.msgbody())?;
-                    Err(WdcError::Buggy) // unreachable
-                }
-            }
-            None => Err(WdcError::WebDriverRemoteConnectionFailed),
+    ///
+    /// Attach to the live session `ssmeta` describes, e.g. one persisted by
+    /// a previous client. See [`Self::attach`].
+    pub fn attach_with_meta(
+        rhost: &str,
+        rport: u16,
+        ssmeta: SessionMeta,
+    ) -> Result<Self, WdcError> {
+        let mut wdc = D::new(rhost, rport);
+
+        wdc.ensure_remote_connected()?;
+        wdc.ssmetas.push(ssmeta);
+
+        if let Err(e) = wdc.get_timeouts() {
+            wdc.ssmetas.clear(); // not a session to delete
+            return Err(e);
         }
+
+        Ok(wdc)
     }
 
-    pub fn navi_to(&self, url: &str) -> Result<&Self, WdcError> {
-        if self.rstream.is_none() {
-            return Err(WdcError::WebDriverRemoteConnectionFailed);
-        };
-        if self.ssmetas.len() == 0 {
-            return Err(WdcError::Buggy);
+    ///
+    /// Whether sessions are left alive when the client is dropped.
+    pub fn detach_on_drop(&self) -> bool {
+        self.detach_on_drop
+    }
+
+    ///
+    /// Leave sessions alive when the client is dropped, so that another
+    /// client can [`Self::attach`] to them later.
+    pub fn set_detach_on_drop(&mut self, arg: bool) -> &mut Self {
+        self.detach_on_drop = arg;
+        self
+    }
+
+    ///
+    /// Send `cmd` to WebDriver server, and return the "value" of its
+    /// response.
+    pub fn execute<C: WebDriverCommand>(&self, cmd: &C) -> Result<C::Output, WdcError> {
+        let mut path = cmd.path();
+        if path.contains(SESSION_ID) {
+            path = path.replace(SESSION_ID, self.cur_ssid()?);
         }
-        let ssid = &self.ssmetas[0].ssid;
-        let mut stream = self.rstream.as_ref().unwrap().lock().unwrap();
-        let mut req = HttpRequestParts::from_scratch();
 
-        let mut mb = Vec::<u8>::new();
-        mb.extend(r#"{"url":""#.as_bytes());
-        mb.extend(url.bytes());
-        mb.extend(r#""}"#.as_bytes());
-
-        run_diag!("navi_to-send_req", {
-            req.http1p1()
-                .post(&format!("/session/{}/url", ssid))
-                .host(&self.raddr())
-                .msgbody_from_slice(&mb)
-                .send_through(&mut stream)
-                .unwrap();
-        });
+        let res: CmdResult<C::Output> = self.send_cmd(cmd.method(), &path, cmd.body())?;
 
-        let resp;
+        Ok(res.value)
+    }
 
-        run_diag!("navi_to-got_resp", {
-            resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0).unwrap();
-        });
+    // commands
 
-        dbgg!(String::from_utf8_lossy(resp.msgbody()));
+    pub fn is_ready(&self) -> Result<(), WdcError> {
+        let res: DrvStatResult = self.send_cmd(HttpMethod::Get, "/status", NO_BODY)?;
 
-        if resp.is_ok() {
-            Ok(self)
+        if res.ready() {
+            Ok(())
+        } else if res.msg() == "Session already started" {
+            Err(WdcError::DriverNotReadyBusySession)
         } else {
-            check_fail_drvcmd(resp.msgbody())?;
-            Err(WdcError::Buggy) // unreachable
+            Err(WdcError::WebDriverNotReady)
         }
     }
 
-    pub fn get_url(&self) -> Result<Vec<u8>, WdcError> {
-        if self.rstream.is_none() {
-            return Err(WdcError::WebDriverRemoteConnectionFailed);
-        };
-        if self.ssmetas.len() == 0 {
-            return Err(WdcError::Buggy);
-        }
-        let ssid = &self.ssmetas[0].ssid;
-        let mut stream = self.rstream.as_ref().unwrap().lock().unwrap();
+    pub fn navi_to(&self, url: &str) -> Result<&Self, WdcError> {
+        let ssid = self.cur_ssid()?;
 
-        let mut req = HttpRequestParts::from_scratch();
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/url", ssid),
+            Some(&NaviToParam { url }),
+        )?;
 
-        req.http1p1()
-            .get(&format!("/session/{}/url", ssid))
-            .host(&self.raddr())
-            .send_through(&mut stream)
-            .unwrap();
+        Ok(self)
+    }
 
-        let resp = HttpResponseParts::from_stream(&mut stream, None, 10, 2).unwrap();
+    ///
+    /// Traverse one step backward in the history of current window.
+    pub fn back(&self) -> Result<&Self, WdcError> {
+        let ssid = self.cur_ssid()?;
 
-        dbgg!(String::from_utf8_lossy(resp.msgbody()));
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/back", ssid),
+            NO_BODY,
+        )?;
 
-        if resp.is_ok() {
-            Ok(resp.msgbody)
-        } else {
-            check_fail_drvcmd(resp.msgbody())?;
-            Err(WdcError::Buggy) // unreachable
-        }
+        Ok(self)
     }
 
-    pub fn find_elem_css(&self, v: &str) -> Result<Vec<u8>, WdcError> {
-        if self.rstream.is_none() {
-            return Err(WdcError::WebDriverRemoteConnectionFailed);
-        };
-        if self.ssmetas.len() == 0 {
-            return Err(WdcError::Buggy);
-        }
-        let ssid = &self.ssmetas[0].ssid;
-        let mut stream = self.rstream.as_ref().unwrap().lock().unwrap();
+    ///
+    /// Traverse one step forward in the history of current window.
+    pub fn forward(&self) -> Result<&Self, WdcError> {
+        let ssid = self.cur_ssid()?;
 
-        let mut req = HttpRequestParts::from_scratch();
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/forward", ssid),
+            NO_BODY,
+        )?;
 
-        let mut conf = FindElemFilter::default();
+        Ok(self)
+    }
 
-        conf.set_using("css selector");
-        conf.set_value(v);
+    ///
+    /// Reload current page.
+    pub fn refresh(&self) -> Result<&Self, WdcError> {
+        let ssid = self.cur_ssid()?;
 
-        let mut mb = Vec::<u8>::new();
-        mb.extend(serde_json::to_vec(&conf).unwrap());
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/refresh", ssid),
+            NO_BODY,
+        )?;
 
-        req.http1p1()
-            .post(&format!("/session/{}/element", ssid))
-            .host(&self.raddr())
-            .msgbody_from_slice(&mb)
-            .send_through(&mut stream)
-            .unwrap();
+        Ok(self)
+    }
 
-        let resp = HttpResponseParts::from_stream(&mut stream, None, 49, 3).unwrap();
+    ///
+    /// Get the title of current page.
+    pub fn title(&self) -> Result<String, WdcError> {
+        let ssid = self.cur_ssid()?;
 
-        dbgg!(String::from_utf8_lossy(resp.msgbody()));
+        let res: GetTitleResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!("/session/{}/title", ssid),
+            NO_BODY,
+        )?;
 
-        if resp.is_ok() {
-            Ok(resp.msgbody)
-        } else {
-            check_fail_drvcmd(resp.msgbody())?;
-            Err(WdcError::Buggy) // unreachable
-        }
+        Ok(res.title().to_string())
     }
 
-    pub fn find_elems_css(&self, v: &str) -> Result<Vec<String>, WdcError> {
-        if self.rstream.is_none() {
-            return Err(WdcError::WebDriverRemoteConnectionFailed);
-        };
-        if self.ssmetas.len() == 0 {
-            return Err(WdcError::Buggy);
-        }
-        let ssid = &self.ssmetas[0].ssid;
-        let mut stream = self.rstream.as_ref().unwrap().lock().unwrap();
-
-        let mut req = HttpRequestParts::from_scratch();
+    pub fn get_url(&self) -> Result<Vec<u8>, WdcError> {
+        let ssid = self.cur_ssid()?;
 
-        let mut conf = FindElemFilter::default();
+        let res: GetUrlResult =
+            self.send_cmd(HttpMethod::Get, &format!("/session/{}/url", ssid), NO_BODY)?;
 
-        conf.set_using("css selector");
-        conf.set_value(v);
+        Ok(res.url().as_bytes().to_vec())
+    }
 
-        let mut mb = Vec::<u8>::new();
-        mb.extend(serde_json::to_vec(&conf).unwrap());
+    ///
+    /// The element referred by `elem`, e.g. one returned by a script.
+    ///
+    /// The reference is not checked, a stale one fails on the first element
+    /// command.
+    pub fn elem_from_ref(&self, elem: &ElemRef) -> WebElement<'_, D> {
+        WebElement::new(self, elem.id())
+    }
 
-        req.http1p1()
-            .post(&format!("/session/{}/elements", ssid))
-            .host(&self.raddr())
-            .msgbody_from_slice(&mb)
-            .send_through(&mut stream)
-            .unwrap();
+    ///
+    /// Find the first element matching `locator`.
+    pub fn find_elem(&self, locator: Locator) -> Result<WebElement<'_, D>, WdcError> {
+        let ssid = self.cur_ssid()?;
+        let conf = FindElemFilter::from(locator);
+
+        let res: FindElemResult = self.send_cmd(
+            HttpMethod::Post,
+            &format!("/session/{}/element", ssid),
+            Some(&conf),
+        )?;
+
+        Ok(WebElement::new(self, res.eleid()))
+    }
 
-        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0).unwrap();
+    ///
+    /// Find all elements matching `locator`.
+    pub fn find_elems(&self, locator: Locator) -> Result<Vec<WebElement<'_, D>>, WdcError> {
+        let ssid = self.cur_ssid()?;
+        let conf = FindElemFilter::from(locator);
+
+        let res: FindElemsResult = self.send_cmd(
+            HttpMethod::Post,
+            &format!("/session/{}/elements", ssid),
+            Some(&conf),
+        )?;
+
+        Ok(res
+            .eleids()
+            .iter()
+            .map(|x| WebElement::new(self, x))
+            .collect())
+    }
 
-        dbgg!(String::from_utf8_lossy(resp.msgbody()));
+    ///
+    /// Find the first element matching CSS selector `v`.
+    #[inline]
+    pub fn find_elem_css(&self, v: &str) -> Result<WebElement<'_, D>, WdcError> {
+        self.find_elem(Locator::Css(v))
+    }
 
-        if resp.is_ok() {
-            match serde_json::from_slice::<FindElemsResult>(resp.msgbody()) {
-                Ok(resp) => Ok(resp.eleids().iter().map(|x| x.to_string()).collect()),
-                _ => Err(WdcError::Buggy),
-            }
-        } else {
-            check_fail_drvcmd(resp.msgbody())?;
-            Err(WdcError::Buggy) // unreachable
-        }
+    ///
+    /// Find all elements matching CSS selector `v`.
+    #[inline]
+    pub fn find_elems_css(&self, v: &str) -> Result<Vec<WebElement<'_, D>>, WdcError> {
+        self.find_elems(Locator::Css(v))
     }
 
-    pub fn elem_send_keys(&self, eleid: &str, keys: &str) -> Result<(), WdcError> {
-        if self.rstream.is_none() {
-            return Err(WdcError::WebDriverRemoteConnectionFailed);
-        };
-        if self.ssmetas.len() == 0 {
-            return Err(WdcError::Buggy);
-        }
-        let ssid = &self.ssmetas[0].ssid;
-        let mut stream = self.rstream.as_ref().unwrap().lock().unwrap();
+    ///
+    /// Find the first element matching `locator`, searching from element
+    /// `eleid` only.
+    pub fn find_elem_from_elem(
+        &self,
+        eleid: &str,
+        locator: Locator,
+    ) -> Result<WebElement<'_, D>, WdcError> {
+        let ssid = self.cur_ssid()?;
+        let conf = FindElemFilter::from(locator);
+
+        let res: FindElemResult = self.send_cmd(
+            HttpMethod::Post,
+            &format!("/session/{}/element/{}/element", ssid, eleid),
+            Some(&conf),
+        )?;
+
+        Ok(WebElement::new(self, res.eleid()))
+    }
 
-        let mut req = HttpRequestParts::from_scratch();
+    ///
+    /// Find all elements matching `locator`, searching from element `eleid`
+    /// only.
+    pub fn find_elems_from_elem(
+        &self,
+        eleid: &str,
+        locator: Locator,
+    ) -> Result<Vec<WebElement<'_, D>>, WdcError> {
+        let ssid = self.cur_ssid()?;
+        let conf = FindElemFilter::from(locator);
+
+        let res: FindElemsResult = self.send_cmd(
+            HttpMethod::Post,
+            &format!("/session/{}/element/{}/elements", ssid, eleid),
+            Some(&conf),
+        )?;
+
+        Ok(res
+            .eleids()
+            .iter()
+            .map(|x| WebElement::new(self, x))
+            .collect())
+    }
 
-        let mut mb = Vec::<u8>::new();
-        mb.extend(r#"{"text":""#.as_bytes());
-        mb.extend(keys.as_bytes());
-        mb.extend(r#""}"#.as_bytes());
+    ///
+    /// Get the shadow root of element `eleid`.
+    pub fn elem_shadow_root(&self, eleid: &str) -> Result<String, WdcError> {
+        let ssid = self.cur_ssid()?;
 
-        req.http1p1()
-            .post(&format!("/session/{}/element/{}/value", ssid, eleid))
-            .host(&self.raddr())
-            .content_type("application/json")
-            .msgbody_from_slice(&mb)
-            .send_through(&mut stream)
-            .unwrap();
+        let res: GetShadowRootResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!("/session/{}/element/{}/shadow", ssid, eleid),
+            NO_BODY,
+        )?;
 
-        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0).unwrap();
+        Ok(res.shadowid().to_string())
+    }
 
-        dbgg!(String::from_utf8_lossy(resp.msgbody()));
+    ///
+    /// Find the first element matching `locator`, searching from shadow root
+    /// `shadowid` only.
+    pub fn find_elem_from_shadow(
+        &self,
+        shadowid: &str,
+        locator: Locator,
+    ) -> Result<WebElement<'_, D>, WdcError> {
+        let ssid = self.cur_ssid()?;
+        let conf = FindElemFilter::from(locator);
+
+        let res: FindElemResult = self.send_cmd(
+            HttpMethod::Post,
+            &format!("/session/{}/shadow/{}/element", ssid, shadowid),
+            Some(&conf),
+        )?;
+
+        Ok(WebElement::new(self, res.eleid()))
+    }
 
-        if resp.is_ok() {
-            // actually value:null needs to be checked, but not necessary
-            Ok(())
-        } else {
-            check_fail_drvcmd(resp.msgbody())?;
-            Err(WdcError::Buggy) // unreachable
-        }
+    ///
+    /// Find all elements matching `locator`, searching from shadow root
+    /// `shadowid` only.
+    pub fn find_elems_from_shadow(
+        &self,
+        shadowid: &str,
+        locator: Locator,
+    ) -> Result<Vec<WebElement<'_, D>>, WdcError> {
+        let ssid = self.cur_ssid()?;
+        let conf = FindElemFilter::from(locator);
+
+        let res: FindElemsResult = self.send_cmd(
+            HttpMethod::Post,
+            &format!("/session/{}/shadow/{}/elements", ssid, shadowid),
+            Some(&conf),
+        )?;
+
+        Ok(res
+            .eleids()
+            .iter()
+            .map(|x| WebElement::new(self, x))
+            .collect())
     }
 
-    pub fn screenshot(&self, save_path: &str) -> Result<(), WdcError> {
-        if self.rstream.is_none() {
-            return Err(WdcError::WebDriverRemoteConnectionFailed);
-        };
-        if self.ssmetas.len() == 0 {
-            return Err(WdcError::Buggy);
-        }
-        let ssid = &self.ssmetas[0].ssid;
-        let mut stream = self.rstream.as_ref().unwrap().lock().unwrap();
+    pub fn elem_send_keys(&self, eleid: &str, keys: &str) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
 
-        let mut req = HttpRequestParts::from_scratch();
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/element/{}/value", ssid, eleid),
+            Some(&ElemSendKeysParam { text: keys }),
+        )?;
 
-        req.http1p1()
-            .get(&format!("/session/{}/screenshot", ssid))
-            .host(&self.raddr())
-            .send_through(&mut stream)
-            .unwrap();
+        Ok(())
+    }
 
-        let resp = HttpResponseParts::from_stream(&mut stream, Some(save_path), 10, 2).unwrap();
+    pub fn elem_click(&self, eleid: &str) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
 
-        dbgg!(String::from_utf8_lossy(resp.msgbody()));
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/element/{}/click", ssid, eleid),
+            NO_BODY,
+        )?;
 
-        if resp.is_ok() {
-            if resp.msgbody_persist().is_some() {
-                Ok(())
-            } else {
-                Err(WdcError::Buggy)
-            }
-        } else {
-            check_fail_drvcmd(resp.msgbody())?;
-            Err(WdcError::Buggy) // unreachable
-        }
+        Ok(())
     }
 
-    // FIXME: should not be &str
-    pub fn screenshot_elem(&self, eleid: &str, save_path: &str) -> Result<(), WdcError> {
-        if self.rstream.is_none() {
-            return Err(WdcError::WebDriverRemoteConnectionFailed);
-        };
-        if self.ssmetas.len() == 0 {
-            return Err(WdcError::Buggy);
-        }
-        let ssid = &self.ssmetas[0].ssid;
-        let mut stream = self.rstream.as_ref().unwrap().lock().unwrap();
+    pub fn elem_clear(&self, eleid: &str) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
 
-        let mut req = HttpRequestParts::from_scratch();
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/element/{}/clear", ssid, eleid),
+            NO_BODY,
+        )?;
 
-        req.http1p1()
-            .get(&format!("/session/{}/element/{}/screenshot", ssid, eleid))
-            .host(&self.raddr())
-            .send_through(&mut stream)
-            .unwrap();
+        Ok(())
+    }
 
-        dbgg!(&req);
+    pub fn elem_text(&self, eleid: &str) -> Result<String, WdcError> {
+        let ssid = self.cur_ssid()?;
 
-        let resp = HttpResponseParts::from_stream(&mut stream, Some(save_path), 10, 2).unwrap();
+        let res: GetElemTextResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!("/session/{}/element/{}/text", ssid, eleid),
+            NO_BODY,
+        )?;
 
-        dbgg!(String::from_utf8_lossy(resp.msgbody()));
+        Ok(res.text().to_string())
+    }
 
-        if resp.is_ok() {
-            if resp.msgbody_persist().is_some() {
-                Ok(())
-            } else {
-                Err(WdcError::Buggy)
-            }
-        } else {
-            dbgg!(&resp);
-            check_fail_drvcmd(resp.msgbody())?;
-            Err(WdcError::Buggy) // unreachable
-        }
+    ///
+    /// Get the attribute `name` of the element, `None` if absent.
+    pub fn elem_attr(&self, eleid: &str, name: &str) -> Result<Option<String>, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: GetElemAttrResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!(
+                "/session/{}/element/{}/attribute/{}",
+                ssid,
+                eleid,
+                httpp::percent_encode(name)
+            ),
+            NO_BODY,
+        )?;
+
+        Ok(res.attr().map(|x| x.to_string()))
     }
 
-    pub fn print_page(&self, save_path: &str) -> Result<(), WdcError> {
-        if self.rstream.is_none() {
-            return Err(WdcError::WebDriverRemoteConnectionFailed);
-        };
-        if self.ssmetas.len() == 0 {
-            return Err(WdcError::Buggy);
-        }
-        let ssid = &self.ssmetas[0].ssid;
-        let mut stream = self.rstream.as_ref().unwrap().lock().unwrap();
+    ///
+    /// Get the property `name` of the element, `Value::Null` if absent.
+    pub fn elem_prop(&self, eleid: &str, name: &str) -> Result<serde_json::Value, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: GetElemPropResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!(
+                "/session/{}/element/{}/property/{}",
+                ssid,
+                eleid,
+                httpp::percent_encode(name)
+            ),
+            NO_BODY,
+        )?;
+
+        Ok(res.prop().clone())
+    }
 
-        let mut req = HttpRequestParts::from_scratch();
+    pub fn elem_css_value(&self, eleid: &str, prop_name: &str) -> Result<String, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: GetElemCssValueResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!(
+                "/session/{}/element/{}/css/{}",
+                ssid,
+                eleid,
+                httpp::percent_encode(prop_name)
+            ),
+            NO_BODY,
+        )?;
+
+        Ok(res.css_value().to_string())
+    }
 
-        let mut mb = Vec::<u8>::new();
-        mb.extend(r#"{"#.as_bytes());
-        mb.extend(r#""background":true"#.as_bytes());
-        mb.extend(r#","orientation":"portrait""#.as_bytes());
-        mb.extend(r#"}"#.as_bytes());
+    pub fn elem_rect(&self, eleid: &str) -> Result<ElemRect, WdcError> {
+        let ssid = self.cur_ssid()?;
 
-        dbgg!(std::str::from_utf8(&mb).unwrap());
+        let res: GetElemRectResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!("/session/{}/element/{}/rect", ssid, eleid),
+            NO_BODY,
+        )?;
 
-        req.http1p1()
-            .post(&format!("/session/{}/print", ssid))
-            .msgbody_from_slice(&mb)
-            .host(&self.raddr())
-            .send_through(&mut stream)
-            .unwrap();
+        Ok(*res.rect())
+    }
 
-        let resp = HttpResponseParts::from_stream(&mut stream, Some(save_path), 10, 2).unwrap();
+    pub fn elem_tag_name(&self, eleid: &str) -> Result<String, WdcError> {
+        let ssid = self.cur_ssid()?;
 
-        dbgg!(String::from_utf8_lossy(resp.msgbody()));
+        let res: GetElemTagNameResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!("/session/{}/element/{}/name", ssid, eleid),
+            NO_BODY,
+        )?;
 
-        if resp.is_ok() {
-            if resp.msgbody_persist().is_some() {
-                Ok(())
-            } else {
-                Err(WdcError::Buggy)
+        Ok(res.tag_name().to_string())
+    }
+
+    #[inline]
+    pub fn is_elem_selected(&self, eleid: &str) -> Result<bool, WdcError> {
+        self.elem_state(eleid, "selected")
+    }
+
+    #[inline]
+    pub fn is_elem_enabled(&self, eleid: &str) -> Result<bool, WdcError> {
+        self.elem_state(eleid, "enabled")
+    }
+
+    #[inline]
+    pub fn is_elem_displayed(&self, eleid: &str) -> Result<bool, WdcError> {
+        self.elem_state(eleid, "displayed")
+    }
+
+    ///
+    /// Take a screenshot of current top-level browsing context, and save it
+    /// to `save_path` as PNG image.
+    pub fn screenshot(&self, save_path: &str) -> Result<(), WdcError> {
+        let mut pbody_file = httpp::create_pbody_file(save_path)?;
+
+        self.screenshot_into(None, &mut pbody_file)
+    }
+
+    ///
+    /// Take a screenshot of current top-level browsing context, as PNG image.
+    ///
+    /// The dimensions of the image can be read with
+    /// [`png_size`](crate::wdcmd::screenshot::png_size).
+    pub fn screenshot_to_vec(&self) -> Result<Vec<u8>, WdcError> {
+        let mut png = Vec::<u8>::new();
+
+        self.screenshot_into(None, &mut png)?;
+
+        Ok(png)
+    }
+
+    ///
+    /// Take a screenshot of current top-level browsing context, and write it
+    /// to `sink` as PNG image.
+    pub fn screenshot_to_writer(&self, sink: &mut dyn Write) -> Result<(), WdcError> {
+        self.screenshot_into(None, sink)
+    }
+
+    ///
+    /// Take a screenshot of the element, and save it to `save_path` as PNG
+    /// image.
+    // FIXME: should not be &str
+    pub fn screenshot_elem(&self, eleid: &str, save_path: &str) -> Result<(), WdcError> {
+        let mut pbody_file = httpp::create_pbody_file(save_path)?;
+
+        self.screenshot_into(Some(eleid), &mut pbody_file)
+    }
+
+    ///
+    /// Take a screenshot of the element, as PNG image.
+    ///
+    /// The dimensions of the image can be read with
+    /// [`png_size`](crate::wdcmd::screenshot::png_size).
+    pub fn screenshot_elem_to_vec(&self, eleid: &str) -> Result<Vec<u8>, WdcError> {
+        let mut png = Vec::<u8>::new();
+
+        self.screenshot_into(Some(eleid), &mut png)?;
+
+        Ok(png)
+    }
+
+    ///
+    /// Take a screenshot of the element, and write it to `sink` as PNG image.
+    pub fn screenshot_elem_to_writer(
+        &self,
+        eleid: &str,
+        sink: &mut dyn Write,
+    ) -> Result<(), WdcError> {
+        self.screenshot_into(Some(eleid), sink)
+    }
+
+    ///
+    /// Print current page as PDF, configured by `opts`, and save it to
+    /// `save_path`.
+    pub fn print_page(&self, opts: &PrintOptions, save_path: &str) -> Result<(), WdcError> {
+        let mut pbody_file = httpp::create_pbody_file(save_path)?;
+
+        self.print_page_into(opts, &mut pbody_file)
+    }
+
+    ///
+    /// Print current page as PDF, configured by `opts`.
+    pub fn print_page_to_vec(&self, opts: &PrintOptions) -> Result<Vec<u8>, WdcError> {
+        let mut pdf = Vec::<u8>::new();
+
+        self.print_page_into(opts, &mut pdf)?;
+
+        Ok(pdf)
+    }
+
+    ///
+    /// Print current page as PDF, configured by `opts`, and write it to
+    /// `sink`.
+    pub fn print_page_to_writer(
+        &self,
+        opts: &PrintOptions,
+        sink: &mut dyn Write,
+    ) -> Result<(), WdcError> {
+        self.print_page_into(opts, sink)
+    }
+
+    ///
+    /// Get the source of current page, and save it to `save_path` if given,
+    /// otherwise return it.
+    pub fn page_src(&self, save_path: Option<&str>) -> Result<Option<Vec<u8>>, WdcError> {
+        match save_path {
+            Some(v) => {
+                let mut pbody_file = httpp::create_pbody_file(v)?;
+                self.page_src_into(&mut pbody_file)?;
+
+                Ok(None)
+            }
+            None => {
+                let mut src = Vec::<u8>::new();
+                self.page_src_into(&mut src)?;
+
+                Ok(Some(src))
             }
-        } else {
-            check_fail_drvcmd(resp.msgbody())?;
-            Err(WdcError::Buggy) // unreachable
         }
     }
 
-    pub fn page_src(&self, save_path: Option<&str>) -> Result<Option<Vec<u8>>, WdcError> {
-        if self.rstream.is_none() {
-            return Err(WdcError::WebDriverRemoteConnectionFailed);
-        };
+    ///
+    /// Get the source of current page, and write it to `sink`.
+    pub fn page_src_to_writer(&self, sink: &mut dyn Write) -> Result<(), WdcError> {
+        self.page_src_into(sink)
+    }
+
+    ///
+    /// Execute `script` synchronously in current browsing context, and return
+    /// its result.
+    ///
+    /// `args` is anything serialized as a sequence, e.g. a tuple, a slice or a
+    /// `Vec`, or `()` for no arguments. A [`WebElement`] is passed as the
+    /// element it refers to. Other values fail with
+    /// [`WdcError::InvalidArgument`] before anything is sent.
+    ///
+    /// The result is deserialized into `T`, use [`ElemRef`] for elements, or
+    /// [`serde_json::Value`] if it is not known beforehand.
+    pub fn exec_sync<T: DeserializeOwned>(
+        &self,
+        script: &str,
+        args: impl Serialize,
+    ) -> Result<T, WdcError> {
+        let ssid = self.cur_ssid()?;
+        let args = script_args(args)?;
+
+        let res: ExecScriptResult<serde_json::Value> = self.send_cmd(
+            HttpMethod::Post,
+            &format!("/session/{}/execute/sync", ssid),
+            Some(&ExecScriptParam { script, args }),
+        )?;
+
+        serde_json::from_value(res.value)
+            .map_err(|e| WdcError::UnexpectedScriptResult(e.to_string()))
+    }
+
+    ///
+    /// Execute `script` asynchronously in current browsing context, and return
+    /// its result.
+    ///
+    /// `args` is anything serialized as a sequence, e.g. a tuple, a slice or a
+    /// `Vec`, or `()` for no arguments. A [`WebElement`] is passed as the
+    /// element it refers to. Other values fail with
+    /// [`WdcError::InvalidArgument`] before anything is sent.
+    ///
+    /// The script signals completion by calling the callback passed as its
+    /// last argument.
+    ///
+    /// The result is deserialized into `T`, use [`ElemRef`] for elements, or
+    /// [`serde_json::Value`] if it is not known beforehand.
+    pub fn exec_async<T: DeserializeOwned>(
+        &self,
+        script: &str,
+        args: impl Serialize,
+    ) -> Result<T, WdcError> {
+        let ssid = self.cur_ssid()?;
+        let args = script_args(args)?;
+
+        let res: ExecScriptResult<serde_json::Value> = self.send_cmd(
+            HttpMethod::Post,
+            &format!("/session/{}/execute/async", ssid),
+            Some(&ExecScriptParam { script, args }),
+        )?;
+
+        serde_json::from_value(res.value)
+            .map_err(|e| WdcError::UnexpectedScriptResult(e.to_string()))
+    }
+
+    pub fn perform_actions(&self, actg: ActionGroup) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        if let Err(id) = self.cur_ssmeta()?.input_state.lock().unwrap().check(&actg) {
+            return Err(WdcError::InputSourceTypeMismatch(id));
+        }
+
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/actions", ssid),
+            Some(&actg),
+        )?;
+        self.cur_ssmeta()?
+            .input_state
+            .lock()
+            .unwrap()
+            .declare(&actg);
+
+        Ok(())
+    }
+
+    ///
+    /// Release all keys and pointer buttons that are currently depressed, and
+    /// forget input sources declared by previous actions.
+    pub fn release_actions(&self) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Delete,
+            &format!("/session/{}/actions", ssid),
+            NO_BODY,
+        )?;
+        self.cur_ssmeta()?.input_state.lock().unwrap().reset();
+
+        Ok(())
+    }
+
+    ///
+    /// Get the handle of current window.
+    pub fn window_handle(&self) -> Result<WindowHandle, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: GetWindowHandleResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!("/session/{}/window", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(res.handle().clone())
+    }
+
+    ///
+    /// Get the handles of all windows in the session.
+    pub fn window_handles(&self) -> Result<Vec<WindowHandle>, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: GetWindowHandlesResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!("/session/{}/window/handles", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(res.handles().to_vec())
+    }
+
+    pub fn switch_to_window(&self, handle: &WindowHandle) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/window", ssid),
+            Some(&SwitchToWindowParam {
+                handle: handle.id(),
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    ///
+    /// Create a new window or tab, the type `wtype` is only a hint to
+    /// WebDriver server.
+    ///
+    /// Note that the new window is **not** switched to automatically.
+    pub fn new_window(&self, wtype: WindowType) -> Result<WindowHandle, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: NewWindowResult = self.send_cmd(
+            HttpMethod::Post,
+            &format!("/session/{}/window/new", ssid),
+            Some(&NewWindowParam { window_type: wtype }),
+        )?;
+
+        Ok(res.handle().clone())
+    }
+
+    ///
+    /// Close current window, return the handles of remaining windows.
+    pub fn close_window(&self) -> Result<Vec<WindowHandle>, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: GetWindowHandlesResult = self.send_cmd(
+            HttpMethod::Delete,
+            &format!("/session/{}/window", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(res.handles().to_vec())
+    }
+
+    pub fn window_rect(&self) -> Result<WindowRect, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: GetWindowRectResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!("/session/{}/window/rect", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(*res.rect())
+    }
+
+    ///
+    /// Resize and reposition current window.
+    ///
+    /// It fails with [`WdcError::UnsupportedOperation`] if the session does
+    /// not support `setWindowRect` capability.
+    pub fn set_window_rect(&self, rect: &WindowRect) -> Result<WindowRect, WdcError> {
+        let ssid = self.cur_ssid()?;
+        if self.cur_ssmeta()?.window_rect == Some(false) {
+            return Err(WdcError::UnsupportedOperation);
+        }
+
+        let res: GetWindowRectResult = self.send_cmd(
+            HttpMethod::Post,
+            &format!("/session/{}/window/rect", ssid),
+            Some(&rect),
+        )?;
+
+        Ok(*res.rect())
+    }
+
+    pub fn maximize_window(&self) -> Result<WindowRect, WdcError> {
+        let ssid = self.cur_ssid()?;
+        if self.cur_ssmeta()?.window_rect == Some(false) {
+            return Err(WdcError::UnsupportedOperation);
+        }
+
+        let res: GetWindowRectResult = self.send_cmd(
+            HttpMethod::Post,
+            &format!("/session/{}/window/maximize", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(*res.rect())
+    }
+
+    pub fn minimize_window(&self) -> Result<WindowRect, WdcError> {
+        let ssid = self.cur_ssid()?;
+        if self.cur_ssmeta()?.window_rect == Some(false) {
+            return Err(WdcError::UnsupportedOperation);
+        }
+
+        let res: GetWindowRectResult = self.send_cmd(
+            HttpMethod::Post,
+            &format!("/session/{}/window/minimize", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(*res.rect())
+    }
+
+    pub fn fullscreen_window(&self) -> Result<WindowRect, WdcError> {
+        let ssid = self.cur_ssid()?;
+        if self.cur_ssmeta()?.window_rect == Some(false) {
+            return Err(WdcError::UnsupportedOperation);
+        }
+
+        let res: GetWindowRectResult = self.send_cmd(
+            HttpMethod::Post,
+            &format!("/session/{}/window/fullscreen", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(*res.rect())
+    }
+
+    ///
+    /// Switch to the frame `id`, as the context of subsequent commands.
+    pub fn switch_to_frame(&self, id: FrameId) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/frame", ssid),
+            Some(&SwitchToFrameParam { id }),
+        )?;
+
+        Ok(())
+    }
+
+    ///
+    /// Switch to the parent of current frame, as the context of subsequent
+    /// commands.
+    pub fn switch_to_parent_frame(&self) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/frame/parent", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(())
+    }
+
+    ///
+    /// Run `f` inside the frame `id`, which is a child of current frame, then
+    /// switch back to current frame.
+    ///
+    /// Current frame is restored even if `f` fails, in which case the error of
+    /// `f` is returned. Calls can be nested, as long as `f` leaves the context
+    /// in the frame it starts with.
+    ///
+    /// [`FrameId::Top`] fails with [`WdcError::InvalidArgument`], since the
+    /// frame it leaves cannot be told by WebDriver server.
+    pub fn within_frame<T, F>(&self, id: FrameId, f: F) -> Result<T, WdcError>
+    where
+        F: FnOnce(&Self) -> Result<T, WdcError>,
+    {
+        if id == FrameId::Top {
+            return Err(WdcError::InvalidArgument(
+                "within_frame cannot switch to top-level context".to_string(),
+            ));
+        }
+
+        self.switch_to_frame(id)?;
+
+        let ret = f(self);
+        let restored = self.switch_to_parent_frame();
+
+        match ret {
+            Ok(v) => restored.map(|_| v),
+            Err(e) => Err(e),
+        }
+    }
+
+    ///
+    /// Get all cookies visible to current page.
+    pub fn all_cookies(&self) -> Result<Vec<Cookie>, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: GetCookiesResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!("/session/{}/cookie", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(res.cookies().to_vec())
+    }
+
+    ///
+    /// Get the cookie `name`, fails with `no such cookie` if absent.
+    pub fn named_cookie(&self, name: &str) -> Result<Cookie, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: GetCookieResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!("/session/{}/cookie/{}", ssid, httpp::percent_encode(name)),
+            NO_BODY,
+        )?;
+
+        Ok(res.cookie().clone())
+    }
+
+    pub fn add_cookie(&self, cookie: &Cookie) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/cookie", ssid),
+            Some(&AddCookieParam { cookie }),
+        )?;
+
+        Ok(())
+    }
+
+    pub fn del_cookie(&self, name: &str) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Delete,
+            &format!("/session/{}/cookie/{}", ssid, httpp::percent_encode(name)),
+            NO_BODY,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn del_all_cookies(&self) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Delete,
+            &format!("/session/{}/cookie", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(())
+    }
+
+    ///
+    /// Dismiss current user prompt, as if "Cancel" clicked.
+    pub fn dismiss_alert(&self) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/alert/dismiss", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(())
+    }
+
+    ///
+    /// Accept current user prompt, as if "OK" clicked.
+    pub fn accept_alert(&self) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/alert/accept", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(())
+    }
+
+    ///
+    /// Get the message of current user prompt.
+    pub fn alert_text(&self) -> Result<Option<String>, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: GetAlertTextResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!("/session/{}/alert/text", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(res.text().map(|x| x.to_string()))
+    }
+
+    ///
+    /// Fill in the input field of current `window.prompt()`.
+    pub fn send_alert_text(&self, text: &str) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/alert/text", ssid),
+            Some(&SendAlertTextParam { text }),
+        )?;
+
+        Ok(())
+    }
+
+    ///
+    /// Get the timeouts of current session.
+    pub fn get_timeouts(&self) -> Result<Timeouts, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: GetTimeoutsResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!("/session/{}/timeouts", ssid),
+            NO_BODY,
+        )?;
+
+        Ok(*res.timeouts())
+    }
+
+    ///
+    /// Set the timeouts of current session, taking effect on subsequent
+    /// commands.
+    pub fn set_timeouts(&self, timeouts: &Timeouts) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        self.send_cmd::<IgnoredAny>(
+            HttpMethod::Post,
+            &format!("/session/{}/timeouts", ssid),
+            Some(timeouts),
+        )?;
+
+        Ok(())
+    }
+
+    pub fn last_session_meta(&self) -> Result<&SessionMeta, WdcError> {
         if self.ssmetas.len() == 0 {
             return Err(WdcError::Buggy);
         }
-        let ssid = &self.ssmetas[0].ssid;
+
+        let lelem = self.ssmetas.last().expect("buggy");
+
+        Ok(lelem)
+    }
+
+    ///
+    /// Metadata of all sessions the client has, in the order they are
+    /// created.
+    pub fn session_metas(&self) -> &[SessionMeta] {
+        &self.ssmetas
+    }
+
+    ///
+    /// Create one more session with `capa`, and return the handle to it.
+    ///
+    /// Only standard capabilities of `capa` are requested, vendor-specific
+    /// ones are ignored. The session is kept until [`Self::end_session`], or
+    /// the client is dropped.
+    pub fn new_session(
+        &mut self,
+        capa: &impl W3cCapaGetter,
+    ) -> Result<SessionHandle<'_, D>, WdcError> {
+        self.w3c_session_singl(capa)?;
+        let idx = self.ssmetas.len() - 1;
+
+        Ok(SessionHandle::new(self, idx))
+    }
+
+    ///
+    /// Return the handle to session `ssid`, through which commands target
+    /// that session.
+    ///
+    /// It fails with [`WdcError::UnknownSession`] if the client has no such
+    /// session.
+    pub fn session(&mut self, ssid: &str) -> Result<SessionHandle<'_, D>, WdcError> {
+        let idx = self.ssidx(ssid)?;
+
+        Ok(SessionHandle::new(self, idx))
+    }
+
+    ///
+    /// Delete session `ssid`.
+    ///
+    /// If it is the current session, the first remaining session becomes
+    /// current. It fails with [`WdcError::UnknownSession`] if the client has
+    /// no such session.
+    pub fn end_session(&mut self, ssid: &str) -> Result<(), WdcError> {
+        let idx = self.ssidx(ssid)?;
+
+        self.del_session(ssid)?;
+        self.ssmetas.remove(idx);
+        if idx < self.cur_ssidx {
+            self.cur_ssidx -= 1;
+        } else if idx == self.cur_ssidx {
+            self.cur_ssidx = 0;
+        }
+
+        Ok(())
+    }
+
+    // private //
+
+    // the JSON string got is unescaped while streaming into `sink`
+    fn page_src_into(&self, sink: &mut dyn Write) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+        if self.rstream.is_none() {
+            return Err(WdcError::WebDriverRemoteConnectionFailed);
+        };
         let mut stream = self.rstream.as_ref().unwrap().lock().unwrap();
 
         let mut req = HttpRequestParts::from_scratch();
@@ -556,185 +1466,227 @@ where
         req.http1p1()
             .get(&format!("/session/{}/source", ssid))
             .host(&self.raddr())
-            .send_through(&mut stream)
-            .unwrap();
+            .send_through(&mut stream)?;
 
-        let resp = HttpResponseParts::from_stream(&mut stream, save_path, 10, 2).unwrap();
+        let mut pbody = StrValueWriter::new(sink);
+        let resp = HttpResponseParts::from_stream(&mut stream, Some(&mut pbody), 0, 0)?;
 
         dbgg!(String::from_utf8_lossy(resp.msgbody()));
 
         if resp.is_ok() {
-            if resp.msgbody_persist().is_some() {
-                Ok(None)
-            } else if resp.msgbody().len() > 0 {
-                Ok(Some(resp.msgbody))
+            if resp.msgbody_persisted() {
+                pbody
+                    .finish()
+                    .map_err(|e| WdcError::BadHttp(e.to_string()))?;
+                Ok(())
             } else {
                 Err(WdcError::Buggy)
             }
         } else {
-            check_fail_drvcmd(resp.msgbody())?;
+            check_fail_drvcmd(&resp)?;
             Err(WdcError::Buggy) // unreachable
         }
     }
 
-    pub fn exec_sync(&self, script: &str, args: Vec<&str>) -> Result<Vec<u8>, WdcError> {
+    // the base64 data got is decoded while streaming into `sink`
+    fn screenshot_into(&self, eleid: Option<&str>, sink: &mut dyn Write) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let requri = match eleid {
+            None => format!("/session/{}/screenshot", ssid),
+            Some(v) => format!("/session/{}/element/{}/screenshot", ssid, v),
+        };
+
+        self.b64_value_into(&requri, sink)
+    }
+
+    ///
+    /// GET `path`, of which the response value is base64 data, and decode it
+    /// into `sink` while streaming.
+    pub(crate) fn b64_value_into(&self, path: &str, sink: &mut dyn Write) -> Result<(), WdcError> {
         if self.rstream.is_none() {
             return Err(WdcError::WebDriverRemoteConnectionFailed);
         };
-        if self.ssmetas.len() == 0 {
-            return Err(WdcError::Buggy);
-        }
-        let ssid = &self.ssmetas[0].ssid;
         let mut stream = self.rstream.as_ref().unwrap().lock().unwrap();
 
         let mut req = HttpRequestParts::from_scratch();
 
-        let mut mb = Vec::<u8>::new();
-        mb.extend(r#"{"script":""#.as_bytes());
-        mb.extend(script.as_bytes());
-        mb.extend(r#"","args":["#.as_bytes());
-        for a in &args {
-            mb.extend(a.as_bytes());
-            mb.extend(",".as_bytes());
-        }
-        if args.len() > 0 {
-            mb.truncate(mb.len() - 1);
-        }
-        mb.extend(r#"]}"#.as_bytes());
-
-        run_diag!("exec-send_req", {
-            req.http1p1()
-                .post(&format!("/session/{}/execute/sync", ssid))
-                .msgbody_from_slice(&mb)
-                .host(&self.raddr())
-                .send_through(&mut stream)
-                .unwrap();
-        });
+        req.http1p1()
+            .get(path)
+            .host(&self.raddr())
+            .send_through(&mut stream)?;
 
-        let resp;
+        dbgg!(&req);
 
-        run_diag!("exec-got_resp", {
-            resp = HttpResponseParts::from_stream(&mut stream, None, 9, 1).unwrap();
-        });
+        let mut pbody = B64ValueWriter::new(sink);
+        let resp = HttpResponseParts::from_stream(&mut stream, Some(&mut pbody), 0, 0)?;
 
         dbgg!(String::from_utf8_lossy(resp.msgbody()));
 
         if resp.is_ok() {
-            Ok(resp.msgbody)
+            if resp.msgbody_persisted() {
+                pbody
+                    .finish()
+                    .map_err(|e| WdcError::BadHttp(e.to_string()))?;
+                Ok(())
+            } else {
+                Err(WdcError::Buggy)
+            }
         } else {
-            check_fail_drvcmd(resp.msgbody())?;
+            check_fail_drvcmd(&resp)?;
             Err(WdcError::Buggy) // unreachable
         }
     }
 
-    pub fn exec_async(&self, script: &str, args: Vec<&str>) -> Result<Vec<u8>, WdcError> {
+    // the base64 data got is decoded while streaming into `sink`
+    fn print_page_into(&self, opts: &PrintOptions, sink: &mut dyn Write) -> Result<(), WdcError> {
+        let ssid = self.cur_ssid()?;
         if self.rstream.is_none() {
             return Err(WdcError::WebDriverRemoteConnectionFailed);
         };
-        if self.ssmetas.len() == 0 {
-            return Err(WdcError::Buggy);
-        }
-        let ssid = &self.ssmetas[0].ssid;
         let mut stream = self.rstream.as_ref().unwrap().lock().unwrap();
 
         let mut req = HttpRequestParts::from_scratch();
 
-        let mut mb = Vec::<u8>::new();
-        mb.extend(r#"{"script":""#.as_bytes());
-        mb.extend(script.as_bytes());
-        mb.extend(r#"","args":["#.as_bytes());
-        for a in &args {
-            mb.extend(a.as_bytes());
-            mb.extend(",".as_bytes());
-        }
-        if args.len() > 0 {
-            mb.truncate(mb.len() - 1);
-        }
-        mb.extend(r#"]}"#.as_bytes());
+        let mb = serde_json::to_vec(opts).unwrap();
+
+        dbgg!(std::str::from_utf8(&mb).unwrap());
 
         req.http1p1()
-            .post(&format!("/session/{}/execute/async", ssid))
+            .post(&format!("/session/{}/print", ssid))
             .msgbody_from_slice(&mb)
             .host(&self.raddr())
-            .send_through(&mut stream)
-            .unwrap();
+            .send_through(&mut stream)?;
 
-        let resp = HttpResponseParts::from_stream(&mut stream, None, 9, 1).unwrap();
+        let mut pbody = B64ValueWriter::new(sink);
+        let resp = HttpResponseParts::from_stream(&mut stream, Some(&mut pbody), 0, 0)?;
 
         dbgg!(String::from_utf8_lossy(resp.msgbody()));
 
         if resp.is_ok() {
-            Ok(resp.msgbody)
+            if resp.msgbody_persisted() {
+                pbody
+                    .finish()
+                    .map_err(|e| WdcError::BadHttp(e.to_string()))?;
+                Ok(())
+            } else {
+                Err(WdcError::Buggy)
+            }
         } else {
-            check_fail_drvcmd(resp.msgbody())?;
+            check_fail_drvcmd(&resp)?;
             Err(WdcError::Buggy) // unreachable
         }
     }
 
-    pub fn perform_actions(&self, actg: ActionGroup) -> Result<(), WdcError> {
+    pub(crate) fn add_ssmeta(
+        &mut self,
+        ssid: String,
+        browser_name: Option<String>,
+        browser_version: Option<String>,
+        platform_name: Option<String>,
+        browser_profile: Option<String>,
+    ) -> &mut SessionMeta {
+        self.ssmetas.push(SessionMeta {
+            ssid,
+            browser_name,
+            browser_version,
+            platform_name,
+            browser_profile,
+            ..Default::default()
+        });
+
+        self.ssmetas.last_mut().expect("buggy")
+    }
+
+    ///
+    /// The id of current session.
+    pub(crate) fn cur_ssid(&self) -> Result<&str, WdcError> {
+        if self.rstream.is_none() {
+            return Err(WdcError::WebDriverRemoteConnectionFailed);
+        };
+
+        Ok(&self.cur_ssmeta()?.ssid)
+    }
+
+    ///
+    /// The metadata of current session.
+    pub(crate) fn cur_ssmeta(&self) -> Result<&SessionMeta, WdcError> {
+        self.ssmetas.get(self.cur_ssidx).ok_or(WdcError::Buggy)
+    }
+
+    // the index of session `ssid` in `ssmetas`
+    fn ssidx(&self, ssid: &str) -> Result<usize, WdcError> {
+        self.ssmetas
+            .iter()
+            .position(|x| x.ssid == ssid)
+            .ok_or_else(|| WdcError::UnknownSession(ssid.to_string()))
+    }
+
+    ///
+    /// Send a command to `path`, and deserialize the whole response into `R`.
+    ///
+    /// This is the path every command goes through, except those streaming
+    /// their responses.
+    pub(crate) fn send_cmd<R: DeserializeOwned>(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        body: Option<&impl Serialize>,
+    ) -> Result<R, WdcError> {
         if self.rstream.is_none() {
             return Err(WdcError::WebDriverRemoteConnectionFailed);
         };
-        if self.ssmetas.len() == 0 {
-            return Err(WdcError::Buggy);
+
+        let mut mb = Vec::<u8>::new();
+        match body {
+            Some(v) => mb.extend(
+                serde_json::to_vec(v).map_err(|e| WdcError::InvalidArgument(e.to_string()))?,
+            ),
+            None if method == HttpMethod::Post => mb.extend(b"{}"),
+            None => {}
         }
-        let ssid = &self.ssmetas[0].ssid;
+
         let mut stream = self.rstream.as_ref().unwrap().lock().unwrap();
 
         let mut req = HttpRequestParts::from_scratch();
 
-        let mut mb_s = serde_json::to_string(&actg).unwrap();
-        mb_s = mb_s.replace("__U__", r"\u");
-        let mb = mb_s.as_bytes();
+        req.http1p1();
+        match method {
+            HttpMethod::Get => req.get(path),
+            HttpMethod::Post => req.post(path),
+            HttpMethod::Delete => req.delete(path),
+        };
+        req.host(&self.raddr());
+        if !mb.is_empty() {
+            req.content_type("application/json").msgbody_from_slice(&mb);
+        }
 
-        req.http1p1()
-            .post(&format!("/session/{}/actions", ssid))
-            .msgbody_from_slice(mb)
-            .host(&self.raddr())
-            .send_through(&mut stream)
-            .unwrap();
+        run_diag!("send_req", {
+            req.send_through(&mut stream)?;
+        });
+
+        let resp;
 
-        let resp = HttpResponseParts::from_stream(&mut stream, None, 9, 1).unwrap();
+        run_diag!("got_resp", {
+            resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0)?;
+        });
 
         dbgg!(String::from_utf8_lossy(resp.msgbody()));
 
         if resp.is_ok() {
-            Ok(())
+            match serde_json::from_slice::<R>(resp.msgbody()) {
+                Ok(res) => Ok(res),
+                Err(_e) => {
+                    dbgg!(_e);
+                    Err(WdcError::Buggy)
+                }
+            }
         } else {
-            check_fail_drvcmd(resp.msgbody())?;
+            check_fail_drvcmd(&resp)?;
             Err(WdcError::Buggy) // unreachable
         }
     }
 
-    pub fn last_session_meta(&self) -> Result<&SessionMeta, WdcError> {
-        if self.ssmetas.len() == 0 {
-            return Err(WdcError::Buggy);
-        }
-
-        let lelem = self.ssmetas.last().expect("buggy");
-
-        Ok(lelem)
-    }
-
-    // private //
-
-    pub(crate) fn add_ssmeta(
-        &mut self,
-        ssid: String,
-        browser_name: Option<String>,
-        browser_version: Option<String>,
-        platform_name: Option<String>,
-        browser_profile: Option<String>,
-    ) {
-        self.ssmetas.push(SessionMeta {
-            ssid,
-            browser_name,
-            browser_version,
-            platform_name,
-            browser_profile,
-        });
-    }
-
     pub(crate) fn raddr(&self) -> String {
         format!("{}:{}", self.rhost, self.rport)
     }
@@ -782,10 +1734,9 @@ where
             .host(&self.raddr())
             .msgbody_from_slice(&mb)
             .content_type("application/json")
-            .send_through(&mut stream)
-            .unwrap();
+            .send_through(&mut stream)?;
 
-        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0).unwrap();
+        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0)?;
 
         dbgg!(String::from_utf8_lossy(resp.msgbody()));
 
@@ -799,7 +1750,8 @@ where
             match deser_result {
                 Ok(sess) => {
                     // self.add_ssid(sess.session_id().to_string());
-                    self.add_ssmeta(sess.session_id().to_string(), None, None, None, None);
+                    self.add_ssmeta(sess.session_id().to_string(), None, None, None, None)
+                        .fill_w3c_capa(&sess);
                     Ok(())
                 }
                 Err(_e) => {
@@ -808,7 +1760,7 @@ where
                 }
             }
         } else {
-            check_fail_drvcmd(resp.msgbody())?;
+            check_fail_drvcmd(&resp)?;
             Err(WdcError::Buggy) // unreachable
         }
     }
@@ -843,10 +1795,9 @@ where
             .host(&self.raddr())
             .msgbody_from_slice(&mb)
             .content_type("application/json")
-            .send_through(&mut stream)
-            .unwrap();
+            .send_through(&mut stream)?;
 
-        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0).unwrap();
+        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0)?;
 
         dbgg!(String::from_utf8_lossy(resp.msgbody()));
 
@@ -859,13 +1810,14 @@ where
 
             match deser_result {
                 Ok(sess) => {
-                    self.add_ssmeta(sess.session_id().to_string(), None, None, None, None);
+                    self.add_ssmeta(sess.session_id().to_string(), None, None, None, None)
+                        .fill_w3c_capa(&sess);
                     Ok(())
                 }
                 _ => Err(WdcError::Buggy),
             }
         } else {
-            check_fail_drvcmd(resp.msgbody())?;
+            check_fail_drvcmd(&resp)?;
             Err(WdcError::Buggy) // unreachable
         }
     }
@@ -908,10 +1860,9 @@ where
             .host(&self.raddr())
             .msgbody_from_slice(&mb)
             .content_type("application/json")
-            .send_through(&mut stream)
-            .unwrap();
+            .send_through(&mut stream)?;
 
-        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0).unwrap();
+        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0)?;
 
         dbgg!(String::from_utf8_lossy(resp.msgbody()));
 
@@ -924,17 +1875,30 @@ where
 
             match deser_result {
                 Ok(sess) => {
-                    self.add_ssmeta(sess.session_id().to_string(), None, None, None, None);
+                    self.add_ssmeta(sess.session_id().to_string(), None, None, None, None)
+                        .fill_w3c_capa(&sess);
                     Ok(())
                 }
                 _ => Err(WdcError::Buggy),
             }
         } else {
-            check_fail_drvcmd(resp.msgbody())?;
+            check_fail_drvcmd(&resp)?;
             Err(WdcError::Buggy) // unreachable
         }
     }
 
+    fn elem_state(&self, eleid: &str, state: &str) -> Result<bool, WdcError> {
+        let ssid = self.cur_ssid()?;
+
+        let res: IsElemStateResult = self.send_cmd(
+            HttpMethod::Get,
+            &format!("/session/{}/element/{}/{}", ssid, eleid, state),
+            NO_BODY,
+        )?;
+
+        Ok(res.state())
+    }
+
     fn del_session(&self, ssid: &str) -> Result<(), WdcError> {
         if self.rstream.is_none() {
             return Err(WdcError::WebDriverRemoteConnectionFailed);
@@ -946,10 +1910,9 @@ where
         req.http1p1()
             .delete(&format!("/session/{}", ssid))
             .host(&self.raddr())
-            .send_through(&mut stream)
-            .unwrap();
+            .send_through(&mut stream)?;
 
-        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0).unwrap();
+        let resp = HttpResponseParts::from_stream(&mut stream, None, 0, 0)?;
 
         dbgg!(String::from_utf8_lossy(resp.headers()));
         dbgg!(String::from_utf8_lossy(resp.msgbody()));
@@ -962,6 +1925,232 @@ where
     }
 }
 
+///
+/// A session of [`WebDrvClient`] picked as the target of commands.
+///
+/// It borrows the client mutably and derefs to it, so any command of the
+/// client can be sent through the handle, and targets the picked session.
+/// Once the handle is dropped, commands target the session they targeted
+/// before.
+pub struct SessionHandle<'c, D>
+where
+    D: CreateWebDrvClient + for<'de, 'c1, 'c2> CreateW3cSession<'de, 'c1, 'c2>,
+{
+    wdc: &'c mut WebDrvClient<D>,
+    prev_ssidx: usize,
+}
+
+impl<'c, D> SessionHandle<'c, D>
+where
+    D: CreateWebDrvClient + for<'de, 'c1, 'c2> CreateW3cSession<'de, 'c1, 'c2>,
+{
+    fn new(wdc: &'c mut WebDrvClient<D>, ssidx: usize) -> Self {
+        let prev_ssidx = wdc.cur_ssidx;
+        wdc.cur_ssidx = ssidx;
+
+        SessionHandle { wdc, prev_ssidx }
+    }
+
+    ///
+    /// The metadata of the session.
+    pub fn meta(&self) -> &SessionMeta {
+        &self.wdc.ssmetas[self.wdc.cur_ssidx]
+    }
+
+    ///
+    /// The id of the session.
+    pub fn session_id(&self) -> &str {
+        &self.meta().ssid
+    }
+}
+
+impl<D> Deref for SessionHandle<'_, D>
+where
+    D: CreateWebDrvClient + for<'de, 'c1, 'c2> CreateW3cSession<'de, 'c1, 'c2>,
+{
+    type Target = WebDrvClient<D>;
+
+    fn deref(&self) -> &Self::Target {
+        self.wdc
+    }
+}
+
+impl<D> Drop for SessionHandle<'_, D>
+where
+    D: CreateWebDrvClient + for<'de, 'c1, 'c2> CreateW3cSession<'de, 'c1, 'c2>,
+{
+    fn drop(&mut self) {
+        self.wdc.cur_ssidx = self.prev_ssidx;
+    }
+}
+
+///
+/// An element found by one of `find_elem*` commands.
+///
+/// It borrows the client that found it, and element commands are sent
+/// through that client.
+pub struct WebElement<'c, D>
+where
+    D: CreateWebDrvClient + for<'de, 'c1, 'c2> CreateW3cSession<'de, 'c1, 'c2>,
+{
+    wdc: &'c WebDrvClient<D>,
+    id: String,
+}
+
+impl<'c, D> WebElement<'c, D>
+where
+    D: CreateWebDrvClient + for<'de, 'c1, 'c2> CreateW3cSession<'de, 'c1, 'c2>,
+{
+    pub(crate) fn new(wdc: &'c WebDrvClient<D>, id: &str) -> Self {
+        WebElement {
+            wdc,
+            id: id.to_string(),
+        }
+    }
+
+    ///
+    /// The web element reference, as known by WebDriver server.
+    #[inline]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    ///
+    /// The client this element is found by.
+    #[inline]
+    pub fn client(&self) -> &'c WebDrvClient<D> {
+        self.wdc
+    }
+
+    ///
+    /// The JSON representation of this element, which is detached from the
+    /// client.
+    pub fn to_ref(&self) -> ElemRef {
+        ElemRef::new(&self.id)
+    }
+
+    ///
+    /// Find the first element matching `locator`, searching from this element
+    /// only.
+    pub fn find_elem(&self, locator: Locator) -> Result<WebElement<'c, D>, WdcError> {
+        self.wdc.find_elem_from_elem(&self.id, locator)
+    }
+
+    ///
+    /// Find all elements matching `locator`, searching from this element only.
+    pub fn find_elems(&self, locator: Locator) -> Result<Vec<WebElement<'c, D>>, WdcError> {
+        self.wdc.find_elems_from_elem(&self.id, locator)
+    }
+
+    ///
+    /// Get the shadow root of this element.
+    pub fn shadow_root(&self) -> Result<String, WdcError> {
+        self.wdc.elem_shadow_root(&self.id)
+    }
+
+    pub fn send_keys(&self, keys: &str) -> Result<(), WdcError> {
+        self.wdc.elem_send_keys(&self.id, keys)
+    }
+
+    pub fn click(&self) -> Result<(), WdcError> {
+        self.wdc.elem_click(&self.id)
+    }
+
+    pub fn clear(&self) -> Result<(), WdcError> {
+        self.wdc.elem_clear(&self.id)
+    }
+
+    pub fn text(&self) -> Result<String, WdcError> {
+        self.wdc.elem_text(&self.id)
+    }
+
+    pub fn attr(&self, name: &str) -> Result<Option<String>, WdcError> {
+        self.wdc.elem_attr(&self.id, name)
+    }
+
+    pub fn prop(&self, name: &str) -> Result<serde_json::Value, WdcError> {
+        self.wdc.elem_prop(&self.id, name)
+    }
+
+    pub fn css_value(&self, prop_name: &str) -> Result<String, WdcError> {
+        self.wdc.elem_css_value(&self.id, prop_name)
+    }
+
+    pub fn rect(&self) -> Result<ElemRect, WdcError> {
+        self.wdc.elem_rect(&self.id)
+    }
+
+    pub fn tag_name(&self) -> Result<String, WdcError> {
+        self.wdc.elem_tag_name(&self.id)
+    }
+
+    pub fn is_selected(&self) -> Result<bool, WdcError> {
+        self.wdc.is_elem_selected(&self.id)
+    }
+
+    pub fn is_enabled(&self) -> Result<bool, WdcError> {
+        self.wdc.is_elem_enabled(&self.id)
+    }
+
+    pub fn is_displayed(&self) -> Result<bool, WdcError> {
+        self.wdc.is_elem_displayed(&self.id)
+    }
+
+    pub fn screenshot(&self, save_path: &str) -> Result<(), WdcError> {
+        self.wdc.screenshot_elem(&self.id, save_path)
+    }
+
+    pub fn screenshot_to_vec(&self) -> Result<Vec<u8>, WdcError> {
+        self.wdc.screenshot_elem_to_vec(&self.id)
+    }
+
+    pub fn screenshot_to_writer(&self, sink: &mut dyn Write) -> Result<(), WdcError> {
+        self.wdc.screenshot_elem_to_writer(&self.id, sink)
+    }
+}
+
+impl<D> Clone for WebElement<'_, D>
+where
+    D: CreateWebDrvClient + for<'de, 'c1, 'c2> CreateW3cSession<'de, 'c1, 'c2>,
+{
+    fn clone(&self) -> Self {
+        WebElement {
+            wdc: self.wdc,
+            id: self.id.clone(),
+        }
+    }
+}
+
+impl<D> PartialEq for WebElement<'_, D>
+where
+    D: CreateWebDrvClient + for<'de, 'c1, 'c2> CreateW3cSession<'de, 'c1, 'c2>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+///
+/// An element is serialized as its web element reference, thus can be passed
+/// to scripts as an argument.
+impl<D> Serialize for WebElement<'_, D>
+where
+    D: CreateWebDrvClient + for<'de, 'c1, 'c2> CreateW3cSession<'de, 'c1, 'c2>,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_ref().serialize(serializer)
+    }
+}
+
+impl<D> std::fmt::Debug for WebElement<'_, D>
+where
+    D: CreateWebDrvClient + for<'de, 'c1, 'c2> CreateW3cSession<'de, 'c1, 'c2>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebElement").field("id", &self.id).finish()
+    }
+}
+
 ///
 /// Initialize a WebDriver client instance.
 ///
@@ -1105,9 +2294,74 @@ mod utst {
     use super::*;
     use std::net::TcpStream; // port probe.
 
+    use std::io::Read;
+    use std::net::TcpListener;
+    use std::thread;
+
+    ///
+    /// Start a fake WebDriver server, which answers each request with the
+    /// next one of `resps`, or nothing if it is `None`, and returns the
+    /// requests got once `resps` are used up.
+    ///
+    /// Requests are served on one connection until the client closes it,
+    /// then on the next one.
+    #[cfg_attr(not(feature = "firefox"), allow(dead_code))]
+    fn fake_server(resps: Vec<Option<&'static [u8]>>) -> (u16, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("server up");
+        let rport = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let mut reqs = vec![];
+            let (mut stream, _) = listener.accept().unwrap();
+            for resp in resps {
+                let req = match fake_server_read(&mut stream) {
+                    Some(v) => v,
+                    None => {
+                        (stream, _) = listener.accept().unwrap();
+                        fake_server_read(&mut stream).expect("request")
+                    }
+                };
+                if let Some(resp) = resp {
+                    stream.write_all(resp).unwrap();
+                }
+                reqs.push(req);
+            }
+
+            reqs
+        });
+
+        (rport, server)
+    }
+
+    // read a whole request, or `None` if the client closes the connection
+    #[cfg_attr(not(feature = "firefox"), allow(dead_code))]
+    fn fake_server_read(stream: &mut TcpStream) -> Option<String> {
+        let mut req = vec![];
+        let mut rbuf = [0u8; 1024];
+        loop {
+            if let Some(i) = req.windows(4).position(|w| w == b"\r\n\r\n") {
+                let head = String::from_utf8_lossy(&req[..i]).to_lowercase();
+                let body_len = head
+                    .lines()
+                    .find_map(|l| l.strip_prefix("content-length:"))
+                    .map_or(0, |v| v.trim().parse::<usize>().unwrap());
+                if req.len() >= i + 4 + body_len {
+                    break;
+                }
+            }
+            let n = stream.read(&mut rbuf).unwrap();
+            if n == 0 {
+                return None;
+            }
+            req.extend(&rbuf[..n]);
+        }
+
+        Some(String::from_utf8(req).unwrap())
+    }
+
     #[cfg(feature = "firefox")]
     mod gecko {
         use super::*;
+        use crate::wdcmd::err::WebDriverErrorCode;
         use crate::GeckoDriver;
         type RendKind = GeckoDriver;
         const REND_HOST: &str = "127.0.0.1";
@@ -1273,6 +2527,306 @@ mod utst {
             assert!(wdc.ssmetas.len() == 1);
             assert!(is_uuid(&wdc.ssmetas[0].ssid));
         }
+
+        #[test]
+        fn execute1() {
+            // a fake server answers exactly one command
+
+            struct SetContext {
+                body: serde_json::Value,
+            }
+
+            impl WebDriverCommand for SetContext {
+                type Body = serde_json::Value;
+                type Output = Option<String>;
+
+                fn method(&self) -> HttpMethod {
+                    HttpMethod::Post
+                }
+
+                fn path(&self) -> String {
+                    "/session/{session id}/moz/context".to_string()
+                }
+
+                fn body(&self) -> Option<&Self::Body> {
+                    Some(&self.body)
+                }
+            }
+
+            let (rport, server) = fake_server(vec![Some(
+                b"HTTP/1.1 200 OK\r\n\
+                  content-type: application/json; charset=utf-8\r\n\
+                  content-length: 14\r\n\
+                  \r\n\
+                  {\"value\":null}",
+            )]);
+
+            let mut wdc = RendKind::new("127.0.0.1", rport);
+            wdc.ensure_remote_connected().expect("connect");
+            wdc.add_ssmeta("b0f2".to_string(), None, None, None, None);
+
+            let cmd = SetContext {
+                body: serde_json::json!({ "context": "chrome\"" }),
+            };
+            let ret = wdc.execute(&cmd);
+            wdc.ssmetas.clear(); // nothing to delete
+            assert_eq!(ret, Ok(None));
+
+            let reqs = server.join().unwrap();
+            assert!(reqs[0].starts_with("POST /session/b0f2/moz/context HTTP/1.1\r\n"));
+            assert!(reqs[0].ends_with(r#"{"context":"chrome\""}"#));
+        }
+
+        #[test]
+        fn attach1() {
+            // a fake server answers "Get Timeouts" twice, then goes away
+
+            let (rport, server) = fake_server(vec![
+                Some(
+                    b"HTTP/1.1 200 OK\r\n\
+                      content-type: application/json; charset=utf-8\r\n\
+                      content-length: 57\r\n\
+                      \r\n\
+                      {\"value\":{\"script\":30000,\"pageLoad\":300000,\"implicit\":0}}",
+                ),
+                Some(
+                    b"HTTP/1.1 404 Not Found\r\n\
+                      content-type: application/json; charset=utf-8\r\n\
+                      content-length: 69\r\n\
+                      \r\n\
+                      {\"value\":{\"error\":\"invalid session id\",\"message\":\"\",\"stacktrace\":\"\"}}",
+                ),
+            ]);
+
+            let mut wdc =
+                WebDrvClient::<RendKind>::attach("127.0.0.1", rport, "b0f2").expect("attach");
+            assert_eq!(wdc.cur_ssid(), Ok("b0f2"));
+            wdc.set_detach_on_drop(true);
+            drop(wdc); // the session is left alone
+
+            let ret = WebDrvClient::<RendKind>::attach("127.0.0.1", rport, "b0f3");
+            assert!(
+                matches!(ret, Err(WdcError::BadDrvCmd(e)) if e.code() == &WebDriverErrorCode::InvalidSessionId)
+            );
+
+            let reqs = server.join().unwrap();
+            assert!(reqs[0].starts_with("GET /session/b0f2/timeouts HTTP/1.1\r\n"));
+            assert!(reqs[1].starts_with("GET /session/b0f3/timeouts HTTP/1.1\r\n"));
+        }
+
+        #[test]
+        fn session_handle1() {
+            let listener = TcpListener::bind("127.0.0.1:0").expect("server up");
+            let rport = listener.local_addr().unwrap().port();
+
+            let mut wdc = RendKind::new("127.0.0.1", rport);
+            wdc.ensure_remote_connected().expect("connect");
+            wdc.add_ssmeta("a0".to_string(), None, None, None, None);
+            wdc.add_ssmeta("b1".to_string(), None, None, None, None);
+            assert_eq!(wdc.cur_ssid(), Ok("a0"));
+
+            {
+                let ss = wdc.session("b1").expect("pick session");
+                assert_eq!(ss.session_id(), "b1");
+                assert_eq!(ss.cur_ssid(), Ok("b1"));
+            }
+            assert_eq!(wdc.cur_ssid(), Ok("a0"));
+
+            let ret = wdc.session("c2").map(|_| ());
+            assert_eq!(ret, Err(WdcError::UnknownSession("c2".to_string())));
+            let ret = wdc.end_session("c2");
+            assert_eq!(ret, Err(WdcError::UnknownSession("c2".to_string())));
+
+            wdc.ssmetas.clear(); // nothing to delete
+        }
+
+        #[test]
+        fn conn_lost1() {
+            // a fake server answers malformed HTTP, then crashes
+
+            use std::io::ErrorKind;
+
+            let (rport, server) = fake_server(vec![Some(b"HTTX/1.1 "), None]);
+
+            let mut wdc = RendKind::new("127.0.0.1", rport);
+            wdc.ensure_remote_connected().expect("connect");
+            wdc.add_ssmeta("a0".to_string(), None, None, None, None);
+
+            let ret = wdc.get_timeouts();
+            assert!(matches!(ret, Err(WdcError::BadHttp(_))));
+
+            let ret = wdc.get_timeouts();
+            assert!(matches!(
+                ret,
+                Err(WdcError::Io(ErrorKind::UnexpectedEof, _))
+            ));
+
+            server.join().unwrap();
+
+            drop(wdc); // deleting session fails quietly
+        }
+
+        #[test]
+        fn pbody_fail1() {
+            // failing to write a streamed body leaves the connection usable
+
+            use std::io::ErrorKind;
+
+            struct BadSink;
+
+            impl Write for BadSink {
+                fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+                    Err(std::io::Error::other("disk full"))
+                }
+
+                fn flush(&mut self) -> std::io::Result<()> {
+                    Ok(())
+                }
+            }
+
+            // big enough to be got by more than one read
+            let b64_resp = |b64: &str| -> &'static [u8] {
+                let body = format!("{{\"value\":\"{}\"}}", b64);
+                let resp = format!(
+                    "HTTP/1.1 200 OK\r\n\
+                     content-type: application/json; charset=utf-8\r\n\
+                     content-length: {}\r\n\
+                     \r\n\
+                     {}",
+                    body.len(),
+                    body
+                );
+                Box::leak(resp.into_bytes().into_boxed_slice())
+            };
+            let (rport, server) = fake_server(vec![
+                Some(b64_resp(&"A".repeat(1_000_000))),
+                Some(b64_resp(&format!("!{}", "A".repeat(999_999)))),
+                Some(
+                    b"HTTP/1.1 200 OK\r\n\
+                      content-type: application/json; charset=utf-8\r\n\
+                      content-length: 57\r\n\
+                      \r\n\
+                      {\"value\":{\"script\":30000,\"pageLoad\":300000,\"implicit\":0}}",
+                ),
+            ]);
+
+            let mut wdc = RendKind::new("127.0.0.1", rport);
+            wdc.ensure_remote_connected().expect("connect");
+            wdc.add_ssmeta("a0".to_string(), None, None, None, None);
+
+            let ret = wdc.screenshot_to_writer(&mut BadSink);
+            assert!(matches!(ret, Err(WdcError::Io(ErrorKind::Other, _))));
+
+            let ret = wdc.screenshot_to_vec();
+            assert!(matches!(ret, Err(WdcError::Io(ErrorKind::InvalidData, _))));
+
+            let ret = wdc.get_timeouts().expect("get timeouts");
+            assert_eq!(ret.script(), Some(30_000));
+            wdc.ssmetas.clear(); // nothing to delete
+
+            let reqs = server.join().unwrap();
+            assert!(reqs[0].starts_with("GET /session/a0/screenshot HTTP/1.1\r\n"));
+            assert!(reqs[2].starts_with("GET /session/a0/timeouts HTTP/1.1\r\n"));
+        }
+
+        #[test]
+        fn elem_attr1() {
+            // names are percent-encoded in the request path
+
+            const VALUE: &[u8] = b"HTTP/1.1 200 OK\r\n\
+                                   content-type: application/json; charset=utf-8\r\n\
+                                   content-length: 13\r\n\
+                                   \r\n\
+                                   {\"value\":\"x\"}";
+            let (rport, server) = fake_server(vec![Some(VALUE), Some(VALUE), Some(VALUE)]);
+
+            let mut wdc = RendKind::new("127.0.0.1", rport);
+            wdc.ensure_remote_connected().expect("connect");
+            wdc.add_ssmeta("a0".to_string(), None, None, None, None);
+
+            let ret = wdc.elem_attr("e1", "data-a b/c?d#e");
+            assert_eq!(ret, Ok(Some("x".to_string())));
+            let ret = wdc.elem_prop("e1", "ü");
+            assert_eq!(ret, Ok(serde_json::json!("x")));
+            let ret = wdc.elem_css_value("e1", "a/b");
+            assert_eq!(ret, Ok("x".to_string()));
+            wdc.ssmetas.clear(); // nothing to delete
+
+            let reqs = server.join().unwrap();
+            assert!(reqs[0].starts_with(
+                "GET /session/a0/element/e1/attribute/data-a%20b%2Fc%3Fd%23e HTTP/1.1\r\n"
+            ));
+            assert!(reqs[1].starts_with("GET /session/a0/element/e1/property/%C3%BC HTTP/1.1\r\n"));
+            assert!(reqs[2].starts_with("GET /session/a0/element/e1/css/a%2Fb HTTP/1.1\r\n"));
+        }
+
+        #[test]
+        fn end_session1() {
+            // ending a session keeps commands on the right session
+
+            const DELETED: &[u8] = b"HTTP/1.1 200 OK\r\n\
+                                     content-type: application/json; charset=utf-8\r\n\
+                                     content-length: 14\r\n\
+                                     \r\n\
+                                     {\"value\":null}";
+            let (rport, server) = fake_server(vec![Some(DELETED), Some(DELETED)]);
+
+            let mut wdc = RendKind::new("127.0.0.1", rport);
+            wdc.ensure_remote_connected().expect("connect");
+            wdc.add_ssmeta("a0".to_string(), None, None, None, None);
+            wdc.add_ssmeta("b1".to_string(), None, None, None, None);
+            wdc.add_ssmeta("c2".to_string(), None, None, None, None);
+            wdc.cur_ssidx = 2;
+
+            // one before current
+            wdc.end_session("a0").expect("end session");
+            assert_eq!(wdc.cur_ssid(), Ok("c2"));
+
+            // current, which is also the last
+            wdc.end_session("c2").expect("end session");
+            assert_eq!(wdc.cur_ssid(), Ok("b1"));
+
+            wdc.ssmetas.clear(); // nothing to delete
+
+            let reqs = server.join().unwrap();
+            assert!(reqs[0].starts_with("DELETE /session/a0 HTTP/1.1\r\n"));
+            assert!(reqs[1].starts_with("DELETE /session/c2 HTTP/1.1\r\n"));
+        }
+
+        #[test]
+        fn window_rect1() {
+            // window commands are refused without "setWindowRect" capability
+
+            let (rport, server) = fake_server(vec![Some(
+                b"HTTP/1.1 200 OK\r\n\
+                  content-type: application/json; charset=utf-8\r\n\
+                  content-length: 57\r\n\
+                  \r\n\
+                  {\"value\":{\"script\":30000,\"pageLoad\":300000,\"implicit\":0}}",
+            )]);
+
+            let mut wdc = RendKind::new("127.0.0.1", rport);
+            wdc.ensure_remote_connected().expect("connect");
+            wdc.add_ssmeta("a0".to_string(), None, None, None, None)
+                .window_rect = Some(false);
+
+            let rect = WindowRect::default();
+            assert_eq!(
+                wdc.set_window_rect(&rect),
+                Err(WdcError::UnsupportedOperation)
+            );
+            assert_eq!(wdc.maximize_window(), Err(WdcError::UnsupportedOperation));
+            assert_eq!(wdc.minimize_window(), Err(WdcError::UnsupportedOperation));
+            assert_eq!(wdc.fullscreen_window(), Err(WdcError::UnsupportedOperation));
+
+            // nothing is sent before the next command
+            wdc.get_timeouts().expect("get timeouts");
+            wdc.ssmetas.clear(); // nothing to delete
+
+            let reqs = server.join().unwrap();
+            assert_eq!(reqs.len(), 1);
+            assert!(reqs[0].starts_with("GET /session/a0/timeouts HTTP/1.1\r\n"));
+        }
     }
 
     #[cfg(feature = "chromium")]
@@ -1434,6 +2988,26 @@ mod utst {
 
     // Auxiliary Functions //
 
+    #[test]
+    fn session_meta1() {
+        let ssmeta = SessionMeta {
+            ssid: "b0f2".to_string(),
+            browser_name: Some("firefox".to_string()),
+            window_rect: Some(true),
+            ..Default::default()
+        };
+        let actual = serde_json::to_string(&ssmeta).unwrap();
+        assert_eq!(
+            actual,
+            r#"{"session_id":"b0f2","browser_name":"firefox","browser_version":null,"platform_name":null,"browser_profile":null,"window_rect":true,"unhandled_prompt_behavior":null}"#
+        );
+
+        let ssmeta: SessionMeta = serde_json::from_str(&actual).unwrap();
+        assert_eq!(ssmeta.session_id(), "b0f2");
+        assert_eq!(ssmeta.browser_name(), Some("firefox"));
+        assert_eq!(ssmeta.window_rect(), Some(true));
+    }
+
     #[allow(unused)]
     fn is_uuid(s: &str) -> bool {
         let re = regex::Regex::new(
@@ -32,6 +32,73 @@ pub(crate) enum HttpError {
     PersistBodyPathNotFound,
     PersistBodyWrite,
     IncompleteFinish,
+    Io(std::io::Error),
+}
+
+impl From<std::io::Error> for HttpError {
+    fn from(e: std::io::Error) -> Self {
+        HttpError::Io(e)
+    }
+}
+
+impl std::fmt::Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpError::Buggy => write!(f, "a possible bug found"),
+            HttpError::InvalidHttpData => write!(f, "invalid HTTP data"),
+            HttpError::InvalidHttpVersion => write!(f, "invalid HTTP version"),
+            HttpError::InvalidContentLength => write!(f, "invalid content length"),
+            HttpError::HeaderNotExistContentLength => write!(f, "no Content-Length header"),
+            HttpError::HeaderNotExistContentType => write!(f, "no Content-Type header"),
+            HttpError::HeaderNotExistReqUri => write!(f, "no request URI"),
+            HttpError::HeaderNotExistConnection => write!(f, "no Connection header"),
+            HttpError::HeaderNotExistUpgrade => write!(f, "no Upgrade header"),
+            HttpError::HeaderNotExistHost => write!(f, "no Host header"),
+            HttpError::HeaderNotExistWsKey => write!(f, "no Sec-WebSocket-Key header"),
+            HttpError::HeaderNotExistWsVer => write!(f, "no Sec-WebSocket-Version header"),
+            HttpError::HeaderNotExistWsAccept => write!(f, "no Sec-WebSocket-Accept header"),
+            HttpError::PersistBodyPathAbsent => write!(f, "no persistent body path"),
+            HttpError::PersistBodyPathNotFound => write!(f, "persistent body path not found"),
+            HttpError::PersistBodyWrite => write!(f, "cannot write persistent body"),
+            HttpError::IncompleteFinish => write!(f, "message body is incomplete"),
+            HttpError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<HttpError> for crate::WdcError {
+    fn from(e: HttpError) -> Self {
+        match e {
+            HttpError::Io(e) => e.into(),
+            e => crate::WdcError::BadHttp(e.to_string()),
+        }
+    }
+}
+
+///
+/// Percent-encode `s` as a single path segment of request URI.
+pub(crate) fn percent_encode(s: &str) -> String {
+    let mut ret = String::with_capacity(s.len());
+
+    for b in s.bytes() {
+        if b.is_ascii_alphanumeric() || b"-._~".contains(&b) {
+            ret.push(b as char);
+        } else {
+            ret.push_str(&format!("%{:02X}", b));
+        }
+    }
+
+    ret
+}
+
+///
+/// Create (or truncate) the file at `path` as a persistent body.
+pub(crate) fn create_pbody_file(path: &str) -> std::io::Result<std::fs::File> {
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
 }
 
 // HttpRequestParts //
@@ -137,9 +204,7 @@ impl HttpRequestParts {
                 let h_name = b"Content-Length:";
                 let h_name_alt = b"content-length:";
                 let h_name_len = h_name.len();
-                if &buf[curi..curi + h_name_len] == h_name
-                    || &buf[curi..curi + h_name_len] == h_name_alt
-                {
+                if buf[curi..].starts_with(h_name) || buf[curi..].starts_with(h_name_alt) {
                     hdl_val_begi = curi + h_name_len;
                     // rfc2616-4.2:
                     // "The field-content does not include any leading or trailing LWS"
@@ -163,17 +228,17 @@ impl HttpRequestParts {
                     }
                     break;
                 } else {
-                    panic!("bug");
+                    return Err(HttpError::InvalidHttpData);
                 }
             }
             curi += 1;
         }
 
         if hdl_val_endi - hdl_val_begi > 0 {
-            Ok((String::from_utf8_lossy(&buf[hdl_val_begi..hdl_val_endi]))
+            (String::from_utf8_lossy(&buf[hdl_val_begi..hdl_val_endi]))
                 .trim()
                 .parse::<usize>()
-                .unwrap())
+                .map_err(|_| HttpError::InvalidContentLength)
         } else {
             Err(HttpError::HeaderNotExistContentLength)
         }
@@ -194,9 +259,7 @@ impl HttpRequestParts {
                 let h_name = b"Connection:";
                 let h_name_alt = b"connection:";
                 let h_name_len = h_name.len();
-                if &buf[curi..curi + h_name_len] == h_name
-                    || &buf[curi..curi + h_name_len] == h_name_alt
-                {
+                if buf[curi..].starts_with(h_name) || buf[curi..].starts_with(h_name_alt) {
                     hdl_val_begi = curi + h_name_len;
                     // rfc2616-4.2:
                     // "The field-content does not include any leading or trailing LWS"
@@ -220,7 +283,7 @@ impl HttpRequestParts {
                     }
                     break;
                 } else {
-                    panic!("bug");
+                    return Err(HttpError::InvalidHttpData);
                 }
             }
             curi += 1;
@@ -248,9 +311,7 @@ impl HttpRequestParts {
                 let h_name = b"Upgrade:";
                 let h_name_alt = b"upgrade:";
                 let h_name_len = h_name.len();
-                if &buf[curi..curi + h_name_len] == h_name
-                    || &buf[curi..curi + h_name_len] == h_name_alt
-                {
+                if buf[curi..].starts_with(h_name) || buf[curi..].starts_with(h_name_alt) {
                     hdl_val_begi = curi + h_name_len;
                     // rfc2616-4.2:
                     // "The field-content does not include any leading or trailing LWS"
@@ -274,7 +335,7 @@ impl HttpRequestParts {
                     }
                     break;
                 } else {
-                    panic!("bug");
+                    return Err(HttpError::InvalidHttpData);
                 }
             }
             curi += 1;
@@ -302,9 +363,7 @@ impl HttpRequestParts {
                 let h_name = b"Host:";
                 let h_name_alt = b"host:";
                 let h_name_len = h_name.len();
-                if &buf[curi..curi + h_name_len] == h_name
-                    || &buf[curi..curi + h_name_len] == h_name_alt
-                {
+                if buf[curi..].starts_with(h_name) || buf[curi..].starts_with(h_name_alt) {
                     hdl_val_begi = curi + h_name_len;
                     // rfc2616-4.2:
                     // "The field-content does not include any leading or trailing LWS"
@@ -328,7 +387,7 @@ impl HttpRequestParts {
                     }
                     break;
                 } else {
-                    panic!("bug");
+                    return Err(HttpError::InvalidHttpData);
                 }
             }
             curi += 1;
@@ -356,9 +415,7 @@ impl HttpRequestParts {
                 let h_name = b"Sec-WebSocket-Key:";
                 let h_name_alt = b"sec-webSocket-key:";
                 let h_name_len = h_name.len();
-                if &buf[curi..curi + h_name_len] == h_name
-                    || &buf[curi..curi + h_name_len] == h_name_alt
-                {
+                if buf[curi..].starts_with(h_name) || buf[curi..].starts_with(h_name_alt) {
                     hdl_val_begi = curi + h_name_len;
                     // rfc2616-4.2:
                     // "The field-content does not include any leading or trailing LWS"
@@ -382,7 +439,7 @@ impl HttpRequestParts {
                     }
                     break;
                 } else {
-                    panic!("bug");
+                    return Err(HttpError::InvalidHttpData);
                 }
             }
             curi += 1;
@@ -410,9 +467,7 @@ impl HttpRequestParts {
                 let h_name = b"Sec-WebSocket-Version:";
                 let h_name_alt = b"sec-websocket-version:";
                 let h_name_len = h_name.len();
-                if &buf[curi..curi + h_name_len] == h_name
-                    || &buf[curi..curi + h_name_len] == h_name_alt
-                {
+                if buf[curi..].starts_with(h_name) || buf[curi..].starts_with(h_name_alt) {
                     hdl_val_begi = curi + h_name_len;
                     // rfc2616-4.2:
                     // "The field-content does not include any leading or trailing LWS"
@@ -436,7 +491,7 @@ impl HttpRequestParts {
                     }
                     break;
                 } else {
-                    panic!("bug");
+                    return Err(HttpError::InvalidHttpData);
                 }
             }
             curi += 1;
@@ -522,7 +577,7 @@ impl HttpRequestParts {
     pub fn send_through(&self, stream: &mut TcpStream) -> Result<(), HttpError> {
         let wbuf = self.to_vec();
         dbgg!(wbuf.len());
-        stream.write_all(&wbuf).unwrap();
+        stream.write_all(&wbuf)?;
 
         Ok(())
     }
@@ -548,7 +603,11 @@ impl HttpRequestParts {
 
         // Request-Line   = Method SP Request-URI SP HTTP-Version CRLF
         loop {
-            stream.read_exact(&mut rbuf[curi..curi + 1]).unwrap(); // !
+            if curi + 1 >= SZ_MAX_RBUF {
+                dbgg!(curi);
+                return Err(HttpError::InvalidHttpData);
+            }
+            stream.read_exact(&mut rbuf[curi..curi + 1])?; // !
             if rbuf[curi] == b' ' {
                 if req_uri_starti == 0 {
                     req_uri_starti = curi + 1;
@@ -558,7 +617,7 @@ impl HttpRequestParts {
             }
             if rbuf[curi] == b'\r' {
                 curi += 1;
-                stream.read_exact(&mut rbuf[curi..curi + 1]).unwrap(); // !
+                stream.read_exact(&mut rbuf[curi..curi + 1])?; // !
                 if rbuf[curi] == b'\n' {
                     headers_starti = curi + 1;
                     curi += 1;
@@ -585,14 +644,14 @@ impl HttpRequestParts {
         // Headers  =  ...CRLFCRLF
         let mut mb_starti = 0usize;
         loop {
-            if curi >= rbuf.len() {
-                dbgg!(rbuf.len());
+            if curi + 3 >= SZ_MAX_RBUF {
+                dbgg!(curi);
                 return Err(HttpError::InvalidHttpData);
             }
-            stream.read_exact(&mut rbuf[curi..curi + 1]).unwrap(); // !
+            stream.read_exact(&mut rbuf[curi..curi + 1])?; // !
             if rbuf[curi] == b'\r' {
                 curi += 1;
-                stream.read_exact(&mut rbuf[curi..curi + 3]).unwrap(); // !
+                stream.read_exact(&mut rbuf[curi..curi + 3])?; // !
                 if &rbuf[curi..curi + 3] == b"\n\r\n" {
                     mb_starti = curi + 3;
                     curi += 3;
@@ -618,7 +677,7 @@ impl HttpRequestParts {
 
         if content_len > 0 && content_len < SZ_MAX_RBUF {
             // FIXME: SZ_MAX_RBUF is inaccurate
-            stream.read_exact(&mut rbuf[0..content_len]).unwrap();
+            stream.read_exact(&mut rbuf[0..content_len])?;
             newone.headers.extend(&rbuf[0..content_len]);
 
             Ok(newone)
@@ -635,7 +694,7 @@ pub(crate) struct HttpResponseParts {
     status: Vec<u8>,
     headers: Vec<u8>,
     pub(crate) msgbody: Vec<u8>,
-    msgbody_persist: Option<String>,
+    msgbody_persisted: bool,
 }
 
 #[allow(unused)]
@@ -661,9 +720,7 @@ impl HttpResponseParts {
                 let h_name = b"Content-Length:";
                 let h_name_alt = b"content-length:";
                 let h_name_len = h_name.len();
-                if &buf[curi..curi + h_name_len] == h_name
-                    || &buf[curi..curi + h_name_len] == h_name_alt
-                {
+                if buf[curi..].starts_with(h_name) || buf[curi..].starts_with(h_name_alt) {
                     hdl_val_begi = curi + h_name_len;
                     // rfc2616-4.2:
                     // "The field-content does not include any leading or trailing LWS"
@@ -687,17 +744,17 @@ impl HttpResponseParts {
                     }
                     break;
                 } else {
-                    panic!("bug");
+                    return Err(HttpError::InvalidHttpData);
                 }
             }
             curi += 1;
         }
 
         if hdl_val_endi - hdl_val_begi > 0 {
-            Ok((String::from_utf8_lossy(&buf[hdl_val_begi..hdl_val_endi]))
+            (String::from_utf8_lossy(&buf[hdl_val_begi..hdl_val_endi]))
                 .trim()
                 .parse::<usize>()
-                .unwrap())
+                .map_err(|_| HttpError::InvalidContentLength)
 
             // Ok(&buf[hdl_val_begi..hdl_val_endi])
         } else {
@@ -720,9 +777,7 @@ impl HttpResponseParts {
                 let h_name = b"Connection:";
                 let h_name_alt = b"connection:";
                 let h_name_len = h_name.len();
-                if &buf[curi..curi + h_name_len] == h_name
-                    || &buf[curi..curi + h_name_len] == h_name_alt
-                {
+                if buf[curi..].starts_with(h_name) || buf[curi..].starts_with(h_name_alt) {
                     hdl_val_begi = curi + h_name_len;
                     // rfc2616-4.2:
                     // "The field-content does not include any leading or trailing LWS"
@@ -746,7 +801,7 @@ impl HttpResponseParts {
                     }
                     break;
                 } else {
-                    panic!("bug");
+                    return Err(HttpError::InvalidHttpData);
                 }
             }
             curi += 1;
@@ -774,9 +829,7 @@ impl HttpResponseParts {
                 let h_name = b"Upgrade:";
                 let h_name_alt = b"upgrade:";
                 let h_name_len = h_name.len();
-                if &buf[curi..curi + h_name_len] == h_name
-                    || &buf[curi..curi + h_name_len] == h_name_alt
-                {
+                if buf[curi..].starts_with(h_name) || buf[curi..].starts_with(h_name_alt) {
                     hdl_val_begi = curi + h_name_len;
                     // rfc2616-4.2:
                     // "The field-content does not include any leading or trailing LWS"
@@ -800,7 +853,7 @@ impl HttpResponseParts {
                     }
                     break;
                 } else {
-                    panic!("bug");
+                    return Err(HttpError::InvalidHttpData);
                 }
             }
             curi += 1;
@@ -828,9 +881,7 @@ impl HttpResponseParts {
                 let h_name = b"Sec-WebSocket-Accept:";
                 let h_name_alt = b"sec-webSocket-accept:";
                 let h_name_len = h_name.len();
-                if &buf[curi..curi + h_name_len] == h_name
-                    || &buf[curi..curi + h_name_len] == h_name_alt
-                {
+                if buf[curi..].starts_with(h_name) || buf[curi..].starts_with(h_name_alt) {
                     hdl_val_begi = curi + h_name_len;
                     // rfc2616-4.2:
                     // "The field-content does not include any leading or trailing LWS"
@@ -854,7 +905,7 @@ impl HttpResponseParts {
                     }
                     break;
                 } else {
-                    panic!("bug");
+                    return Err(HttpError::InvalidHttpData);
                 }
             }
             curi += 1;
@@ -923,11 +974,10 @@ impl HttpResponseParts {
         &self.msgbody
     }
 
-    pub fn msgbody_persist(&self) -> Option<&str> {
-        match &self.msgbody_persist {
-            None => None,
-            Some(m) => Some(m),
-        }
+    ///
+    /// Check response body has been written to the persistent body.
+    pub fn msgbody_persisted(&self) -> bool {
+        self.msgbody_persisted
     }
 
     ///
@@ -936,36 +986,48 @@ impl HttpResponseParts {
         &self.status == b"200 OK"
     }
 
+    ///
+    /// The response status code, 0 if malformed.
+    pub fn status_code(&self) -> u16 {
+        self.status
+            .split(|x| *x == b' ')
+            .next()
+            .and_then(|x| std::str::from_utf8(x).ok())
+            .and_then(|x| x.parse().ok())
+            .unwrap_or(0)
+    }
+
     pub fn from_scratch() -> Self {
         HttpResponseParts {
             httpver: vec![],
             status: vec![],
             headers: vec![],
             msgbody: vec![],
-            msgbody_persist: None,
+            msgbody_persisted: false,
         }
     }
 
     ///
     /// Contruct an response instance from TcpStream.
     ///
-    /// The second parameter stands for "persistent body path", which is
-    /// a path to a file whose content is the response body got from `stream`.
-    /// The `write_after` controls the number of bytes from start of original
+    /// The second parameter stands for "persistent body", which is a writer
+    /// receiving the response body got from `stream`, in which case the body
+    /// is not kept in memory. It is used only if the status is 200, otherwise
+    /// the body is kept in memory as usual. If writing to it fails, the rest
+    /// of the body is still read and dropped, so that `stream` stays usable.
+    /// The `insig_head` controls the number of bytes from start of original
     /// response body should be ignored, the `insig_tail` indicates the number
-    /// of bytes from the end of original response body should be ignored, these
-    /// two are accomplish differently to each other, the former is used
-    /// while consuming the stream, the latter is used just before closing the
-    /// persistent response body file.
+    /// of bytes from the end of original response body should be ignored.
     pub fn from_stream(
         stream: &mut TcpStream,
-        pbody_path: Option<&str>,
+        pbody: Option<&mut dyn Write>,
         insig_head: usize,
         insig_tail: usize,
     ) -> Result<Self, HttpError> {
         let mut template = Self::from_scratch();
         let (mut curi, mut nexi) = (0, 0);
         const SZ_MAX_RBUF: usize = 8192;
+        const SZ_MAX_RESERVE: usize = 1024 * 1024 * 1;
         let mut rbuf = [0u8; SZ_MAX_RBUF]; // Read Buffer
 
         // Status Line
@@ -983,7 +1045,7 @@ impl HttpResponseParts {
 
         // http ver
         run_diag!("http_ver", {
-            stream.read_exact(&mut rbuf[0..9]).unwrap(); // rfc2616-6.1, mandated SP
+            stream.read_exact(&mut rbuf[0..9])?; // rfc2616-6.1, mandated SP
             match &rbuf[0..9] {
                 b"HTTP/0.9 " | b"HTTP/1.0 " | b"HTTP/1.1 " | b"HTTP/2.0 " | b"HTTP/3.0 " => {
                     template.httpver.extend(&rbuf[0..8]);
@@ -996,7 +1058,7 @@ impl HttpResponseParts {
 
         // status code
         // run_diag!("status_code", {
-        stream.read_exact(&mut rbuf[0..4]).unwrap(); // rfc2616-6.1, mandated SP
+        stream.read_exact(&mut rbuf[0..4])?; // rfc2616-6.1, mandated SP
 
         // FIXME: skip verify content?
         template.status.extend(&rbuf[0..4]);
@@ -1016,8 +1078,9 @@ impl HttpResponseParts {
         // });
         // });
 
-        let (mut insig_head_tmp, insig_tail_tmp); // head maybe mutated
-        if &template.status == b"200 " {
+        let status_ok = &template.status == b"200 ";
+        let (insig_head_tmp, insig_tail_tmp);
+        if status_ok {
             (insig_head_tmp, insig_tail_tmp) = (insig_head, insig_tail);
         } else {
             (insig_head_tmp, insig_tail_tmp) = (0, 0);
@@ -1030,10 +1093,14 @@ impl HttpResponseParts {
         let mut curi = 0usize;
         let reason_endi;
         loop {
-            stream.read_exact(&mut rbuf[curi..curi + 1]).unwrap();
+            if curi + 1 >= SZ_MAX_RBUF {
+                dbgg!(curi);
+                return Err(HttpError::InvalidHttpData);
+            }
+            stream.read_exact(&mut rbuf[curi..curi + 1])?;
             if &rbuf[curi..curi + 1] == b"\r" {
                 curi += 1;
-                stream.read_exact(&mut rbuf[curi..curi + 1]).unwrap();
+                stream.read_exact(&mut rbuf[curi..curi + 1])?;
                 if &rbuf[curi..curi + 1] == b"\n" {
                     reason_endi = curi - 1; // rfc2616-6.1, mandated SPC
                     break;
@@ -1057,10 +1124,14 @@ impl HttpResponseParts {
         let mut curi = 0usize;
         let headers_endi; // excluded
         loop {
-            stream.read_exact(&mut rbuf[curi..curi + 1]).unwrap();
+            if curi + 3 >= SZ_MAX_RBUF {
+                dbgg!(curi);
+                return Err(HttpError::InvalidHttpData);
+            }
+            stream.read_exact(&mut rbuf[curi..curi + 1])?;
             if &rbuf[curi..curi + 1] == b"\r" {
                 curi += 1;
-                stream.read_exact(&mut rbuf[curi..curi + 3]).unwrap();
+                stream.read_exact(&mut rbuf[curi..curi + 3])?;
                 if &rbuf[curi..curi + 3] == b"\n\r\n" {
                     curi += 3;
                     headers_endi = curi;
@@ -1089,59 +1160,59 @@ impl HttpResponseParts {
         match template.get_content_length() {
             Ok(msgbody_len) => {
                 dbgg!(msgbody_len);
-                if pbody_path.is_none() {
+                if msgbody_len < insig_head_tmp + insig_tail_tmp {
+                    // keep the stream usable for the next response
+                    discard_body(stream, msgbody_len)?;
+                    return Err(HttpError::InvalidContentLength);
+                }
+                let pbody = if status_ok { pbody } else { None };
+                if pbody.is_none() {
                     // a trivial-size body
                     let msgbody_begi = 0;
                     let msgbody_endi = msgbody_len;
 
                     if msgbody_len <= SZ_MAX_RBUF {
-                        stream
-                            .read_exact(&mut rbuf[msgbody_begi..msgbody_endi])
-                            .unwrap();
+                        stream.read_exact(&mut rbuf[msgbody_begi..msgbody_endi])?;
                         template.msgbody.extend(
                             &rbuf[msgbody_begi + insig_head_tmp..msgbody_endi - insig_tail_tmp],
                         );
                     } else {
-                        let mut big_rbuf = Vec::<u8>::with_capacity(msgbody_len);
-                        big_rbuf.resize(msgbody_len, 0);
-                        if big_rbuf.len() < msgbody_len {
-                            panic!("buggy");
+                        // Content-Length is not trusted for the up-front
+                        // reservation, the buffer grows as data comes
+                        let mut big_rbuf =
+                            Vec::<u8>::with_capacity(std::cmp::min(msgbody_len, SZ_MAX_RESERVE));
+                        let nread = stream.take(msgbody_len as u64).read_to_end(&mut big_rbuf)?;
+                        if nread < msgbody_len {
+                            return Err(HttpError::Io(std::io::ErrorKind::UnexpectedEof.into()));
                         }
-                        stream
-                            .read_exact(&mut big_rbuf[msgbody_begi..msgbody_endi])
-                            .unwrap();
                         template.msgbody.extend(
                             &big_rbuf[msgbody_begi + insig_head_tmp..msgbody_endi - insig_tail_tmp],
                         );
                     }
                 } else {
                     // a non-trivial body
-                    let pbody_path = pbody_path.unwrap();
-                    let pbody_file = std::fs::OpenOptions::new()
-                        .write(true)
-                        .create(true)
-                        .truncate(true)
-                        // .append(true)
-                        .open(pbody_path);
-                    if let Err(_e) = pbody_file {
-                        return Err(HttpError::PersistBodyPathNotFound);
-                    }
-                    let mut pbody_file = pbody_file.unwrap();
+                    let pbody = pbody.unwrap();
 
-                    let mut nleft = msgbody_len;
-                    // read from socket, then write to local storage
-                    let mut read_buf = [0u8; 1024 * 1024 * 1];
+                    // the significant part of body, [sig_begi, sig_endi)
+                    let (sig_begi, sig_endi) = (insig_head_tmp, msgbody_len - insig_tail_tmp);
+                    let mut nread_total = 0usize;
+                    // the body is still read through once writing fails, to
+                    // keep the stream usable for the next response
+                    let mut write_err = None;
+                    // read from socket, then write to persistent body
+                    let mut read_buf = vec![0u8; 1024 * 1024 * 1];
 
                     // diagnostic only variables
                     let mut _n_sys_socket_read = 0usize;
                     let mut _n_sys_disk_write = 0usize;
 
                     loop {
-                        if nleft == 0 {
+                        if nread_total == msgbody_len {
                             break;
                         }
-                        dbgg!(nleft);
-                        match stream.read(&mut read_buf) {
+                        dbgg!(msgbody_len - nread_total);
+                        let nwant = std::cmp::min(read_buf.len(), msgbody_len - nread_total);
+                        match stream.read(&mut read_buf[..nwant]) {
                             Ok(nread) => {
                                 dbgg!(nread);
                                 if nread > 0 {
@@ -1149,25 +1220,21 @@ impl HttpResponseParts {
                                         _n_sys_socket_read += 1;
                                     }
 
-                                    // dbgg!(nleft, nread, insig_head_tmp);
-                                    if nleft < nread {
-                                        return Err(HttpError::InvalidContentLength);
-                                    }
-                                    nleft -= nread;
+                                    let chunk_begi = nread_total;
+                                    nread_total += nread;
 
-                                    if insig_head_tmp > nread {
-                                        // dbgg!("skip persist write", insig_head_tmp,
-                                        // nread);
-                                        insig_head_tmp -= nread;
+                                    let begi = sig_begi.clamp(chunk_begi, nread_total);
+                                    let endi = sig_endi.clamp(chunk_begi, nread_total);
+                                    if begi == endi || write_err.is_some() {
                                         continue;
                                     }
-                                    if let Err(_e) =
-                                        pbody_file.write_all(&read_buf[insig_head_tmp..nread])
+                                    if let Err(e) = pbody
+                                        .write_all(&read_buf[begi - chunk_begi..endi - chunk_begi])
                                     {
-                                        dbgg!(_e);
-                                        return Err(HttpError::PersistBodyWrite);
+                                        dbgg!(&e);
+                                        write_err = Some(e);
+                                        continue;
                                     }
-                                    insig_head_tmp = 0;
                                     {
                                         _n_sys_disk_write += 1;
                                     }
@@ -1178,24 +1245,22 @@ impl HttpResponseParts {
                                     break;
                                 }
                             }
-                            Err(_e) => {
-                                dbgg!("stream read failed", _e);
-                                break;
+                            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                            Err(e) => {
+                                dbgg!("stream read failed", &e);
+                                return Err(HttpError::Io(e));
                             }
                         }
                     } // loop
 
-                    if nleft == 0 {
+                    if let Some(e) = write_err {
+                        return Err(HttpError::Io(e));
+                    }
+                    if nread_total == msgbody_len {
                         dbgg!("all data read done", _n_sys_socket_read, _n_sys_disk_write);
-                        template.msgbody_persist = Some(pbody_path.to_string());
-                        // start to truncate from file tail
-                        let final_fsize = msgbody_len - insig_head - insig_tail;
-                        dbgg!(msgbody_len, insig_head_tmp, final_fsize);
-                        pbody_file
-                            .set_len(final_fsize as u64)
-                            .expect("failed to truncate file");
+                        template.msgbody_persisted = true;
                     } else {
-                        dbgg!(nleft);
+                        dbgg!(msgbody_len - nread_total);
                         return Err(HttpError::IncompleteFinish);
                     }
                 }
@@ -1211,11 +1276,21 @@ impl HttpResponseParts {
 
     pub fn send_through(&self, stream: &mut TcpStream) -> Result<(), HttpError> {
         let wbuf = self.to_vec();
-        stream.write_all(&wbuf).unwrap();
+        stream.write_all(&wbuf)?;
         Ok(())
     }
 }
 
+// read and drop `len` bytes of body from `stream`
+fn discard_body(stream: &mut TcpStream, len: usize) -> Result<(), HttpError> {
+    let ndiscarded = std::io::copy(&mut stream.take(len as u64), &mut std::io::sink())?;
+    if ndiscarded < len as u64 {
+        return Err(HttpError::IncompleteFinish);
+    }
+
+    Ok(())
+}
+
 impl std::fmt::Debug for HttpResponseParts {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         write!(
@@ -1250,6 +1325,8 @@ mod utst {
 
     // use super::*; // not strictly unit tests, but along with integrated ones.
 
+    use super::percent_encode;
+    use super::HttpError;
     use super::HttpRequestParts;
     use super::HttpResponseParts;
 
@@ -1530,9 +1607,15 @@ mod utst {
         // Client
         match TcpStream::connect(&remote_sockaddr) {
             Ok(mut stream) => {
+                let mut bodygot_file = OpenOptions::new()
+                    .write(true)
+                    .create(true)
+                    .truncate(true)
+                    .open(BODYGOT_FILE)
+                    .unwrap();
                 let resp = HttpResponseParts::from_stream(
                     &mut stream,
-                    Some(BODYGOT_FILE),
+                    Some(&mut bodygot_file),
                     SZ_BODY_START,
                     0,
                 )
@@ -1550,8 +1633,7 @@ mod utst {
                     )
                     .as_bytes()
                 );
-                assert_eq!(resp.msgbody_persist().is_some(), true);
-                assert_eq!(resp.msgbody_persist().unwrap(), BODYGOT_FILE);
+                assert!(resp.msgbody_persisted());
 
                 let mut bodydata = OpenOptions::new().read(true).open(RANDOM_FILE).unwrap();
                 let mut bodygot = OpenOptions::new().read(true).open(BODYGOT_FILE).unwrap();
@@ -1686,9 +1768,15 @@ mod utst {
         // Client
         match TcpStream::connect(&remote_sockaddr) {
             Ok(mut stream) => {
+                let mut bodygot_file = OpenOptions::new()
+                    .write(true)
+                    .create(true)
+                    .truncate(true)
+                    .open(BODYGOT_FILE)
+                    .unwrap();
                 let resp = HttpResponseParts::from_stream(
                     &mut stream,
-                    Some(BODYGOT_FILE),
+                    Some(&mut bodygot_file),
                     SZ_BODY_START,
                     0,
                 )
@@ -1706,8 +1794,7 @@ mod utst {
                     )
                     .as_bytes()
                 );
-                assert_eq!(resp.msgbody_persist().is_some(), true);
-                assert_eq!(resp.msgbody_persist().unwrap(), BODYGOT_FILE);
+                assert!(resp.msgbody_persisted());
 
                 let mut bodydata = OpenOptions::new().read(true).open(RANDOM_FILE).unwrap();
                 let mut bodygot = OpenOptions::new().read(true).open(BODYGOT_FILE).unwrap();
@@ -1843,9 +1930,15 @@ mod utst {
         // Client
         match TcpStream::connect(&remote_sockaddr) {
             Ok(mut stream) => {
+                let mut bodygot_file = OpenOptions::new()
+                    .write(true)
+                    .create(true)
+                    .truncate(true)
+                    .open(BODYGOT_FILE)
+                    .unwrap();
                 let resp = HttpResponseParts::from_stream(
                     &mut stream,
-                    Some(BODYGOT_FILE),
+                    Some(&mut bodygot_file),
                     SZ_BODY_START,
                     SZ_DISMISS_TAIL,
                 )
@@ -1863,8 +1956,7 @@ mod utst {
                     )
                     .as_bytes()
                 );
-                assert_eq!(resp.msgbody_persist().is_some(), true);
-                assert_eq!(resp.msgbody_persist().unwrap(), BODYGOT_FILE);
+                assert!(resp.msgbody_persisted());
 
                 const SZ_FRAG: usize = 256;
                 const FRAG_COUNT: usize = SZ_RANDOM / SZ_FRAG; // careful, should x%y==0
@@ -1905,4 +1997,65 @@ mod utst {
 
         server_thread.join().unwrap();
     }
+
+    #[test]
+    fn _6() {
+        assert_eq!(percent_encode("session-id_1.x~"), "session-id_1.x~");
+        assert_eq!(percent_encode("a b/c?d#e%"), "a%20b%2Fc%3Fd%23e%25");
+        assert_eq!(percent_encode("ü"), "%C3%BC");
+    }
+
+    #[test]
+    fn _7() {
+        // oversized status line or headers are rejected, rather than
+        // overflowing the read buffer
+        let listener = TcpListener::bind("127.0.0.1:0").expect("server up failed");
+        let remote_sockaddr = listener.local_addr().unwrap();
+        let server_thread = thread::spawn(move || {
+            let msgs = [
+                format!("HTTP/1.1 200 {}\r\n\r\n", "K".repeat(9000)),
+                format!("HTTP/1.1 200 OK\r\n{}\r\n", "x-pad: 0\r\n".repeat(900)),
+                format!("GET / HTTP/1.1\r\n{}\r\n", "x-pad: 0\r\n".repeat(900)),
+            ];
+            for msg in msgs {
+                let (mut stream, _) = listener.accept().unwrap();
+                let _ = stream.write_all(msg.as_bytes());
+            }
+        });
+
+        for _ in 0..2 {
+            let mut stream = TcpStream::connect(remote_sockaddr).unwrap();
+            let ret = HttpResponseParts::from_stream(&mut stream, None, 0, 0);
+            assert!(matches!(ret, Err(HttpError::InvalidHttpData)));
+        }
+        let stream = TcpStream::connect(remote_sockaddr).unwrap();
+        let ret = HttpRequestParts::from_stream(&stream);
+        assert!(matches!(ret, Err(HttpError::InvalidHttpData)));
+
+        server_thread.join().unwrap();
+    }
+
+    #[test]
+    fn _8() {
+        // a huge Content-Length does not reserve memory up front
+        let listener = TcpListener::bind("127.0.0.1:0").expect("server up failed");
+        let remote_sockaddr = listener.local_addr().unwrap();
+        let server_thread = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let _ = stream.write_all(
+                b"HTTP/1.1 200 OK\r\n\
+                  content-length: 1152921504606846976\r\n\
+                  \r\n\
+                  {\"value\":null}",
+            );
+        });
+
+        let mut stream = TcpStream::connect(remote_sockaddr).unwrap();
+        let ret = HttpResponseParts::from_stream(&mut stream, None, 0, 0);
+        assert!(
+            matches!(ret, Err(HttpError::Io(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof)
+        );
+
+        server_thread.join().unwrap();
+    }
 }
@@ -66,12 +66,11 @@
 //!    let wdc: WebDrvClient<GeckoDriver> = wdc::init("127.0.0.1", 4444, 10)?;
 //!    let url = "https://www.w3.org/Consortium/facts.html";
 //!
-//!    let js_result = wdc.navi_to(url)?.exec_sync(
+//!    let w3c_history: String = wdc.navi_to(url)?.exec_sync(
 //!        "return document.getElementById('history').nextElementSibling.innerText;",
-//!        vec![],
+//!        (),
 //!    )?;
 //!
-//!    let w3c_history = String::from_utf8_lossy(&js_result);
 //!    assert!(w3c_history.contains("Tim Berners-Lee"));
 //!    assert!(w3c_history.contains("World Wide Web"));
 //!    assert!(w3c_history.contains("HTML"));
@@ -94,6 +93,13 @@ pub mod wdcmd;
 #[allow(clippy::len_zero, clippy::identity_op)]
 mod httpp;
 
+mod b64;
+
+mod strv;
+
+#[cfg(feature = "firefox")]
+mod zip;
+
 #[cfg(feature = "bidi")]
 #[allow(clippy::len_zero, clippy::identity_op, clippy::needless_range_loop)]
 mod wsp;
@@ -124,9 +130,93 @@ pub enum WdcError {
     ///
     /// The command cannot be processed successfully by WebDriver server.
     ///
-    /// The first field corresponds to the "error" field of standard WebDriver
-    /// error response, the second corresponds to the "message" field.
-    BadDrvCmd(String, String),
+    /// The field carries the error code, message, HTTP status and other
+    /// details of standard WebDriver error response, see
+    /// [`wdcmd::err::WebDriverError`].
+    BadDrvCmd(wdcmd::err::WebDriverError),
+    ///
+    /// An input source id is reused with a different type, the field is the
+    /// id.
+    ///
+    /// Input sources are kept until actions are released, see
+    /// [`WebDrvClient::release_actions`].
+    InputSourceTypeMismatch(String),
+    ///
+    /// The script result cannot be deserialized into the requested type, the
+    /// field is the deserialization error.
+    UnexpectedScriptResult(String),
+    ///
+    /// The CDP command result cannot be deserialized into the requested type,
+    /// the field is the deserialization error.
+    UnexpectedCdpResult(String),
+    ///
+    /// An argument is rejected by the client before anything is sent to
+    /// WebDriver server, the field is the reason.
+    InvalidArgument(String),
+    ///
+    /// The session is not one of the client, the field is the session id.
+    UnknownSession(String),
+    ///
+    /// An I/O error occurred while talking to WebDriver server, the fields
+    /// are the kind and message of the error.
+    ///
+    /// For example, the kind is [`std::io::ErrorKind::UnexpectedEof`] if the
+    /// connection is closed by a crashed driver.
+    Io(std::io::ErrorKind, String),
+    ///
+    /// The HTTP message got from WebDriver server is malformed, the field is
+    /// the description.
+    BadHttp(String),
+    ///
+    /// The WebSocket message got from WebDriver server is malformed, or the
+    /// WebSocket handshake fails, the field is the description.
+    BadWebSocket(String),
+}
+
+impl std::fmt::Display for WdcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WdcError::Buggy => write!(f, "a possible bug found"),
+            WdcError::BusyCreateSession => write!(f, "WebDriver server is creating a session"),
+            WdcError::DriverNotReadyBusySession => {
+                write!(f, "WebDriver server is not ready, session already started")
+            }
+            WdcError::NotReadyForNewSession => {
+                write!(f, "WebDriver server is not ready for new session")
+            }
+            WdcError::UnsupportedOperation => write!(f, "unsupported operation"),
+            WdcError::WebDriverNotReady => write!(f, "WebDriver server is not ready"),
+            WdcError::WebDriverRemoteConnectionFailed => {
+                write!(f, "cannot connect to WebDriver server")
+            }
+            WdcError::BadDrvCmd(e) => write!(f, "bad command: {}", e),
+            WdcError::InputSourceTypeMismatch(id) => {
+                write!(f, "input source {} is reused with a different type", id)
+            }
+            WdcError::UnexpectedScriptResult(e) => write!(f, "unexpected script result: {}", e),
+            WdcError::UnexpectedCdpResult(e) => write!(f, "unexpected CDP result: {}", e),
+            WdcError::InvalidArgument(e) => write!(f, "invalid argument: {}", e),
+            WdcError::UnknownSession(id) => write!(f, "unknown session {}", id),
+            WdcError::Io(_, msg) => write!(f, "I/O error: {}", msg),
+            WdcError::BadHttp(e) => write!(f, "bad HTTP message: {}", e),
+            WdcError::BadWebSocket(e) => write!(f, "bad WebSocket message: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for WdcError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WdcError::BadDrvCmd(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for WdcError {
+    fn from(e: std::io::Error) -> Self {
+        WdcError::Io(e.kind(), e.to_string())
+    }
 }
 
 #[allow(clippy::len_zero)]
@@ -144,9 +234,13 @@ pub use genericdrv::init;
 pub use genericdrv::init_singl;
 pub use genericdrv::CreateW3cSession;
 pub use genericdrv::CreateWebDrvClient;
+pub use genericdrv::HttpMethod;
 pub use genericdrv::RendVendor;
+pub use genericdrv::SessionHandle;
 pub use genericdrv::SessionMeta;
+pub use genericdrv::WebDriverCommand;
 pub use genericdrv::WebDrvClient;
+pub use genericdrv::WebElement;
 
 #[cfg(feature = "firefox")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "firefox")))]
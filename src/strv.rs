@@ -0,0 +1,241 @@
+// Copyright (C) 2023 Michael Lee <imichael2e2@proton.me/...@gmail.com>
+//
+// Licensed under the MIT License <LICENSE-MIT or
+// https://opensource.org/license/mit> or the GNU General Public License,
+// Version 3.0 or any later version <LICENSE-GPL or
+// https://www.gnu.org/licenses/gpl-3.0.txt>, at your option.
+//
+// This file may not be copied, modified, or distributed except except in
+// compliance with either of the licenses.
+//
+
+use std::io::{Error, ErrorKind, Write};
+
+#[derive(Debug, PartialEq)]
+enum ValueState {
+    Head,
+    Body,
+    Escape,
+    Unicode,
+    Tail,
+}
+
+///
+/// A writer that takes a WebDriver response body of the form
+/// `{"value":"<string>"}`, and writes the unescaped string to the inner
+/// writer.
+///
+/// The JSON string is unescaped as it comes, so the response body does not
+/// need to be buffered. [`StrValueWriter::finish`] must be called once all
+/// data has been written.
+pub(crate) struct StrValueWriter<W: Write> {
+    inner: W,
+    state: ValueState,
+    seen_colon: bool,
+    unit: u16,
+    nhex: usize,
+    high: Option<u16>,
+}
+
+impl<W: Write> StrValueWriter<W> {
+    pub(crate) fn new(inner: W) -> Self {
+        StrValueWriter {
+            inner,
+            state: ValueState::Head,
+            seen_colon: false,
+            unit: 0,
+            nhex: 0,
+            high: None,
+        }
+    }
+
+    ///
+    /// Check the value is complete.
+    pub(crate) fn finish(mut self) -> Result<W, Error> {
+        if self.state != ValueState::Tail {
+            return Err(invalid_data("incomplete value"));
+        }
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    // a high surrogate not followed by a low one
+    fn flush_high(&mut self, out: &mut Vec<u8>) {
+        if self.high.take().is_some() {
+            push_char(char::REPLACEMENT_CHARACTER, out);
+        }
+    }
+
+    fn feed_unit(&mut self, unit: u16, out: &mut Vec<u8>) {
+        match unit {
+            0xd800..=0xdbff => {
+                self.flush_high(out);
+                self.high = Some(unit);
+            }
+            0xdc00..=0xdfff => match self.high.take() {
+                Some(high) => {
+                    let v = 0x10000 + (((high as u32) - 0xd800) << 10) + ((unit as u32) - 0xdc00);
+                    push_char(
+                        char::from_u32(v).unwrap_or(char::REPLACEMENT_CHARACTER),
+                        out,
+                    );
+                }
+                None => push_char(char::REPLACEMENT_CHARACTER, out),
+            },
+            _ => {
+                self.flush_high(out);
+                push_char(
+                    char::from_u32(unit as u32).unwrap_or(char::REPLACEMENT_CHARACTER),
+                    out,
+                );
+            }
+        }
+    }
+}
+
+impl<W: Write> Write for StrValueWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Error> {
+        let mut out = Vec::<u8>::with_capacity(buf.len());
+
+        for &c in buf {
+            match self.state {
+                ValueState::Head => {
+                    if c == b':' {
+                        self.seen_colon = true;
+                    } else if c == b'"' && self.seen_colon {
+                        self.state = ValueState::Body;
+                    }
+                }
+                ValueState::Body => match c {
+                    b'"' => {
+                        self.flush_high(&mut out);
+                        self.state = ValueState::Tail;
+                    }
+                    b'\\' => self.state = ValueState::Escape,
+                    _ => {
+                        self.flush_high(&mut out);
+                        out.push(c);
+                    }
+                },
+                ValueState::Escape => {
+                    let unescaped = match c {
+                        b'"' | b'\\' | b'/' => c,
+                        b'b' => b'\x08',
+                        b'f' => b'\x0c',
+                        b'n' => b'\n',
+                        b'r' => b'\r',
+                        b't' => b'\t',
+                        b'u' => {
+                            (self.unit, self.nhex) = (0, 0);
+                            self.state = ValueState::Unicode;
+                            continue;
+                        }
+                        _ => return Err(invalid_data("invalid escape character")),
+                    };
+                    self.flush_high(&mut out);
+                    out.push(unescaped);
+                    self.state = ValueState::Body;
+                }
+                ValueState::Unicode => {
+                    let digit = match (c as char).to_digit(16) {
+                        Some(v) => v as u16,
+                        None => return Err(invalid_data("invalid unicode escape")),
+                    };
+                    self.unit = self.unit << 4 | digit;
+                    self.nhex += 1;
+                    if self.nhex == 4 {
+                        self.feed_unit(self.unit, &mut out);
+                        self.state = ValueState::Body;
+                    }
+                }
+                ValueState::Tail => {}
+            }
+        }
+
+        self.inner.write_all(&out)?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        self.inner.flush()
+    }
+}
+
+fn push_char(c: char, out: &mut Vec<u8>) {
+    let mut utf8 = [0u8; 4];
+    out.extend(c.encode_utf8(&mut utf8).as_bytes());
+}
+
+fn invalid_data(msg: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod utst {
+    use super::*;
+
+    fn unescape_value(body: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut w = StrValueWriter::new(Vec::<u8>::with_capacity(body.len()));
+        w.write_all(body)?;
+
+        w.finish()
+    }
+
+    #[test]
+    fn _1() {
+        assert_eq!(unescape_value(br#"{"value":""}"#).unwrap(), b"");
+        assert_eq!(
+            unescape_value(br#"{"value":"<p>hi</p>"}"#).unwrap(),
+            b"<p>hi</p>"
+        );
+        assert_eq!(
+            unescape_value(br#"{ "value" : "a\"b\\c\/d" }"#).unwrap(),
+            br#"a"b\c/d"#
+        );
+        assert_eq!(
+            unescape_value(br#"{"value":"\b\f\n\r\t"}"#).unwrap(),
+            b"\x08\x0c\n\r\t"
+        );
+        assert_eq!(
+            unescape_value(br#"{"value":"\u003Chtml\u003e"}"#).unwrap(),
+            b"<html>"
+        );
+    }
+
+    #[test]
+    fn _2() {
+        // fed byte by byte, as if from a slow stream
+        let body = "{\"value\":\"caf\u{e9} \\u00e9\\n\u{1f600} \\ud83d\\ude00\"}";
+        let mut w = StrValueWriter::new(vec![]);
+        for b in body.as_bytes().chunks(1) {
+            w.write_all(b).unwrap();
+        }
+        assert_eq!(
+            w.finish().unwrap(),
+            "caf\u{e9} \u{e9}\n\u{1f600} \u{1f600}".as_bytes()
+        );
+    }
+
+    #[test]
+    fn _3() {
+        // lone surrogates
+        assert_eq!(
+            unescape_value(br#"{"value":"\ud83dx\ude00\ud83d"}"#).unwrap(),
+            "\u{fffd}x\u{fffd}\u{fffd}".as_bytes()
+        );
+        assert_eq!(
+            unescape_value("{\"value\":\"\\ud83d\u{1f600}\"}".as_bytes()).unwrap(),
+            "\u{fffd}\u{1f600}".as_bytes()
+        );
+    }
+
+    #[test]
+    fn _4() {
+        assert!(unescape_value(br#"{"value":"abc"#).is_err());
+        assert!(unescape_value(br#"{"value":"\x"}"#).is_err());
+        assert!(unescape_value(br#"{"value":"\u12g4"}"#).is_err());
+        assert!(unescape_value(br#"{"value":"\u12"#).is_err());
+    }
+}
@@ -10,6 +10,7 @@
 //
 
 use std::borrow::Cow;
+use std::collections::HashMap;
 
 // action group
 
@@ -83,6 +84,23 @@ impl<'ag> ActionGroup<'ag> {
     }
 }
 
+impl ActionGroup<'_> {
+    ///
+    /// The input sources declared by this group, by id and type.
+    pub fn input_sources(&self) -> impl Iterator<Item = (&str, InputSourceType)> {
+        self.actions.iter().filter_map(|act| act.input_source())
+    }
+}
+
+///
+/// The type of an input source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputSourceType {
+    Key,
+    Pointer,
+    Wheel,
+}
+
 #[derive(Debug)]
 pub enum ActionKind<'ag> {
     None,
@@ -91,6 +109,61 @@ pub enum ActionKind<'ag> {
     Wheel(AnyAction<'ag, WheelSubAction>),
 }
 
+impl ActionKind<'_> {
+    fn input_source(&self) -> Option<(&str, InputSourceType)> {
+        match self {
+            ActionKind::None => None,
+            ActionKind::Key(act) => Some((&act.id, InputSourceType::Key)),
+            ActionKind::Pointer(act) => Some((&act.id, InputSourceType::Pointer)),
+            ActionKind::Wheel(act) => Some((&act.id, InputSourceType::Wheel)),
+        }
+    }
+}
+
+// input state
+
+///
+/// The input sources known to a session, mirroring the server side input
+/// state until actions get released.
+#[derive(Debug, Default)]
+pub(crate) struct InputState {
+    sources: HashMap<String, InputSourceType>,
+}
+
+impl InputState {
+    ///
+    /// Check input sources of `actg` against the known ones, return the first
+    /// id that is already known as a different type.
+    pub(crate) fn check(&self, actg: &ActionGroup) -> Result<(), String> {
+        let mut pending = HashMap::<&str, InputSourceType>::new();
+
+        for (id, stype) in actg.input_sources() {
+            let known = pending.get(id).or_else(|| self.sources.get(id)).copied();
+            match known {
+                Some(v) if v != stype => return Err(id.to_string()),
+                _ => {
+                    pending.insert(id, stype);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Record input sources of `actg`, which should have passed
+    /// [`InputState::check`].
+    pub(crate) fn declare(&mut self, actg: &ActionGroup) {
+        for (id, stype) in actg.input_sources() {
+            self.sources.entry(id.to_string()).or_insert(stype);
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.sources.clear();
+    }
+}
+
 // any action
 
 #[derive(Debug)]
@@ -476,30 +549,19 @@ mod ser {
                 } // _ => {}
             }
 
-            match self.value {
-                KbdValue::Backspace => {
-                    state.serialize_field("value", "__U__E003")?;
-                }
-                KbdValue::Tab => {
-                    state.serialize_field("value", "__U__E004")?;
-                }
-                KbdValue::Enter => {
-                    // state.serialize_field("value", "\\uE007")?;
-                    //    \----> can be solved by serde's extra feature,
-                    state.serialize_field("value", "__U__E007")?;
-                }
-                KbdValue::LeftShift => {
-                    state.serialize_field("value", "__U__E008")?;
-                }
-                KbdValue::LeftControl => {
-                    state.serialize_field("value", "__U__E009")?;
-                }
-
-                KbdValue::Unicode(s) => {
-                    state.serialize_field("value", s)?;
-                }
-                _ => {}
-            }
+            let value = match self.value {
+                KbdValue::Backspace => "\u{E003}",
+                KbdValue::Tab => "\u{E004}",
+                KbdValue::Enter => "\u{E007}",
+                KbdValue::LeftShift => "\u{E008}",
+                KbdValue::LeftControl => "\u{E009}",
+                KbdValue::LeftAlt => "\u{E00A}",
+                KbdValue::RightShift => "\u{E050}",
+                KbdValue::RightControl => "\u{E051}",
+                KbdValue::RightAlt => "\u{E052}",
+                KbdValue::Unicode(s) => s,
+            };
+            state.serialize_field("value", value)?;
 
             state.end()
         }
@@ -650,21 +712,21 @@ mod utst {
                 Token::Str("type"),
                 Token::Str("keyDown"),
                 Token::Str("value"),
-                Token::Str("__U__E004"),
+                Token::Str("\u{E004}"),
                 Token::StructEnd,
                 // seq ele seq ele
                 Token::Struct { name: "-", len: 1 },
                 Token::Str("type"),
                 Token::Str("keyUp"),
                 Token::Str("value"),
-                Token::Str("__U__E004"),
+                Token::Str("\u{E004}"),
                 Token::StructEnd,
                 // seq ele seq ele
                 Token::Struct { name: "-", len: 1 },
                 Token::Str("type"),
                 Token::Str("keyDown"),
                 Token::Str("value"),
-                Token::Str("__U__E004"),
+                Token::Str("\u{E004}"),
                 Token::StructEnd,
                 // seq ele seq ele
                 Token::Struct { name: "-", len: 1 },
@@ -678,7 +740,7 @@ mod utst {
                 Token::Str("type"),
                 Token::Str("keyDown"),
                 Token::Str("value"),
-                Token::Str("__U__E007"),
+                Token::Str("\u{E007}"),
                 Token::StructEnd,
                 Token::SeqEnd,
                 Token::StructEnd,
@@ -693,15 +755,13 @@ mod utst {
 
         let bs = into_json_bytes(&actg);
 
-        fn post_process_into_string(bs: &[u8]) -> String {
-            let mut s = String::from_utf8_lossy(bs).to_string();
-            s = s.replace("__U__", r"\u");
-            s
-        }
-
+        // special keys are sent as is, equivalent to their escaped form
         assert_eq!(
-            post_process_into_string(&bs),
-            r#"{"actions":[{"type":"key","id":"key-act-id","actions":[{"type":"keyDown","value":"\uE004"},{"type":"keyUp","value":"\uE004"},{"type":"keyDown","value":"\uE004"},{"type":"keyDown","value":"X"},{"type":"keyDown","value":"\uE007"}]}]}"#
+            serde_json::from_slice::<serde_json::Value>(&bs).unwrap(),
+            serde_json::from_str::<serde_json::Value>(
+                r#"{"actions":[{"type":"key","id":"key-act-id","actions":[{"type":"keyDown","value":"\uE004"},{"type":"keyUp","value":"\uE004"},{"type":"keyDown","value":"\uE004"},{"type":"keyDown","value":"X"},{"type":"keyDown","value":"\uE007"}]}]}"#
+            )
+            .unwrap()
         );
     }
 
@@ -910,14 +970,14 @@ mod utst {
                 Token::Str("type"),
                 Token::Str("keyDown"),
                 Token::Str("value"),
-                Token::Str("__U__E004"),
+                Token::Str("\u{E004}"),
                 Token::StructEnd,
                 // seq ele seq ele
                 Token::Struct { name: "-", len: 1 },
                 Token::Str("type"),
                 Token::Str("keyDown"),
                 Token::Str("value"),
-                Token::Str("__U__E007"),
+                Token::Str("\u{E007}"),
                 Token::StructEnd,
                 Token::SeqEnd,
                 Token::StructEnd,
@@ -926,13 +986,53 @@ mod utst {
             ],
         );
 
-        let mut s = serde_json::to_string(&actg).unwrap();
+        let s = serde_json::to_string(&actg).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&s).unwrap(),
+            serde_json::from_str::<serde_json::Value>(
+                r#"{"actions":[{"type":"pointer","id":"back-to-index","actions":[{"type":"pointerDown","button":3}]},{"type":"wheel","id":"scroll-down-to-somewhere","actions":[{"type":"scroll","x":0,"y":0,"deltaX":14,"deltaY":15}]},{"type":"key","id":"focus-ele-and-enter","actions":[{"type":"keyDown","value":"\uE004"},{"type":"keyDown","value":"\uE007"}]}]}"#
+            )
+            .unwrap()
+        );
+    }
 
-        s = s.replace("__U__", r"\u");
+    #[test]
+    fn _5() {
+        let mut state = InputState::default();
 
+        let mut actg = ActionGroup::default();
+        actg.add_key_act("kbd").add_subact().keydown().tab();
+        actg.add_pointer_act("mouse").add_subact().ptr_down();
         assert_eq!(
-            &s,
-            r#"{"actions":[{"type":"pointer","id":"back-to-index","actions":[{"type":"pointerDown","button":3}]},{"type":"wheel","id":"scroll-down-to-somewhere","actions":[{"type":"scroll","x":0,"y":0,"deltaX":14,"deltaY":15}]},{"type":"key","id":"focus-ele-and-enter","actions":[{"type":"keyDown","value":"\uE004"},{"type":"keyDown","value":"\uE007"}]}]}"#
+            actg.input_sources().collect::<Vec<_>>(),
+            vec![
+                ("kbd", InputSourceType::Key),
+                ("mouse", InputSourceType::Pointer)
+            ]
         );
+        assert_eq!(state.check(&actg), Ok(()));
+        assert!(state.sources.is_empty());
+        state.declare(&actg);
+
+        let mut actg = ActionGroup::default();
+        actg.add_key_act("kbd").add_subact().keyup().tab();
+        assert_eq!(state.check(&actg), Ok(()));
+
+        let mut actg = ActionGroup::default();
+        actg.add_wheel_act("scroll").add_subact().scroll_amt(0, 10);
+        actg.add_wheel_act("mouse").add_subact().scroll_amt(0, 10);
+        assert_eq!(state.check(&actg), Err("mouse".to_string()));
+        assert!(!state.sources.contains_key("scroll"));
+
+        let mut actg = ActionGroup::default();
+        actg.add_wheel_act("dup").add_subact().scroll_amt(0, 10);
+        actg.add_key_act("dup").add_subact().keydown().tab();
+        assert_eq!(state.check(&actg), Err("dup".to_string()));
+
+        state.reset();
+        let mut actg = ActionGroup::default();
+        actg.add_wheel_act("mouse").add_subact().scroll_amt(0, 10);
+        assert_eq!(state.check(&actg), Ok(()));
     }
 }
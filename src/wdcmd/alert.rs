@@ -0,0 +1,53 @@
+// Copyright (C) 2023 Michael Lee <imichael2e2@proton.me/...@gmail.com>
+//
+// Licensed under the MIT License <LICENSE-MIT or
+// https://opensource.org/license/mit> or the GNU General Public License,
+// Version 3.0 or any later version <LICENSE-GPL or
+// https://www.gnu.org/licenses/gpl-3.0.txt>, at your option.
+//
+// This file may not be copied, modified, or distributed except except in
+// compliance with either of the licenses.
+//
+
+///
+/// Get details of "Get Alert Text" command processing result.
+pub trait GetAlertTextResultGetter {
+    fn text(&self) -> Option<&str>;
+}
+
+///
+/// The "Get Alert Text" command processing result.
+///
+/// The value is `null` if the user prompt has no message.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetAlertTextResult {
+    value: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct SendAlertTextParam<'a> {
+    pub(crate) text: &'a str,
+}
+
+impl GetAlertTextResultGetter for GetAlertTextResult {
+    fn text(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod utst {
+    use super::*;
+
+    #[test]
+    fn _1() {
+        let res: GetAlertTextResult = serde_json::from_str(r#"{"value":"hi"}"#).unwrap();
+        assert_eq!(res.text(), Some("hi"));
+
+        let res: GetAlertTextResult = serde_json::from_str(r#"{"value":null}"#).unwrap();
+        assert_eq!(res.text(), None);
+
+        let actual = serde_json::to_string(&SendAlertTextParam { text: "a\"b" }).unwrap();
+        assert_eq!(actual, r#"{"text":"a\"b"}"#);
+    }
+}
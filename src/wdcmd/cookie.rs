@@ -0,0 +1,219 @@
+// Copyright (C) 2023 Michael Lee <imichael2e2@proton.me/...@gmail.com>
+//
+// Licensed under the MIT License <LICENSE-MIT or
+// https://opensource.org/license/mit> or the GNU General Public License,
+// Version 3.0 or any later version <LICENSE-GPL or
+// https://www.gnu.org/licenses/gpl-3.0.txt>, at your option.
+//
+// This file may not be copied, modified, or distributed except except in
+// compliance with either of the licenses.
+//
+
+///
+/// The `SameSite` attribute of a cookie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum SameSite {
+    Lax,
+    Strict,
+    None,
+}
+
+///
+/// A [cookie](https://w3c.github.io/webdriver/#dfn-serialized-cookie),
+/// as serialized by WebDriver.
+///
+/// Only `name` and `value` are mandatory, the others are left to WebDriver
+/// server if absent.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cookie {
+    name: String,
+    value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secure: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    http_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expiry: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    same_site: Option<SameSite>,
+}
+
+impl Cookie {
+    pub fn new(name: &str, value: &str) -> Self {
+        Cookie {
+            name: name.to_string(),
+            value: value.to_string(),
+            ..Default::default()
+        }
+    }
+
+    // getter
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    #[inline]
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    #[inline]
+    pub fn domain(&self) -> Option<&str> {
+        self.domain.as_deref()
+    }
+
+    #[inline]
+    pub fn secure(&self) -> Option<bool> {
+        self.secure
+    }
+
+    #[inline]
+    pub fn http_only(&self) -> Option<bool> {
+        self.http_only
+    }
+
+    ///
+    /// The expiry time, in seconds since Unix Epoch.
+    #[inline]
+    pub fn expiry(&self) -> Option<u64> {
+        self.expiry
+    }
+
+    #[inline]
+    pub fn same_site(&self) -> Option<SameSite> {
+        self.same_site
+    }
+
+    // setter
+
+    pub fn set_path(&mut self, arg: &str) -> &mut Self {
+        self.path = Some(arg.to_string());
+        self
+    }
+
+    pub fn set_domain(&mut self, arg: &str) -> &mut Self {
+        self.domain = Some(arg.to_string());
+        self
+    }
+
+    pub fn set_secure(&mut self, arg: bool) -> &mut Self {
+        self.secure = Some(arg);
+        self
+    }
+
+    pub fn set_http_only(&mut self, arg: bool) -> &mut Self {
+        self.http_only = Some(arg);
+        self
+    }
+
+    ///
+    /// Set the expiry time, in seconds since Unix Epoch.
+    pub fn set_expiry(&mut self, arg: u64) -> &mut Self {
+        self.expiry = Some(arg);
+        self
+    }
+
+    pub fn set_same_site(&mut self, arg: SameSite) -> &mut Self {
+        self.same_site = Some(arg);
+        self
+    }
+}
+
+///
+/// Get details of "Get All Cookies" command processing result.
+pub trait GetCookiesResultGetter {
+    fn cookies(&self) -> &[Cookie];
+}
+
+///
+/// Get details of "Get Named Cookie" command processing result.
+pub trait GetCookieResultGetter {
+    fn cookie(&self) -> &Cookie;
+}
+
+///
+/// The "Get All Cookies" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetCookiesResult {
+    value: Vec<Cookie>,
+}
+
+///
+/// The "Get Named Cookie" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetCookieResult {
+    value: Cookie,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct AddCookieParam<'a> {
+    pub(crate) cookie: &'a Cookie,
+}
+
+impl GetCookiesResultGetter for GetCookiesResult {
+    fn cookies(&self) -> &[Cookie] {
+        &self.value
+    }
+}
+
+impl GetCookieResultGetter for GetCookieResult {
+    fn cookie(&self) -> &Cookie {
+        &self.value
+    }
+}
+
+#[cfg(test)]
+mod utst {
+    use super::*;
+
+    #[test]
+    fn _1() {
+        let cookie = Cookie::new("foo", "bar");
+        let actual = serde_json::to_string(&AddCookieParam { cookie: &cookie }).unwrap();
+        assert_eq!(actual, r#"{"cookie":{"name":"foo","value":"bar"}}"#);
+
+        let mut cookie = Cookie::new("foo", "bar");
+        cookie
+            .set_path("/")
+            .set_http_only(true)
+            .set_expiry(1700000000)
+            .set_same_site(SameSite::Lax);
+        let actual = serde_json::to_string(&cookie).unwrap();
+        assert_eq!(
+            actual,
+            r#"{"name":"foo","value":"bar","path":"/","httpOnly":true,"expiry":1700000000,"sameSite":"Lax"}"#
+        );
+    }
+
+    #[test]
+    fn _2() {
+        let res: GetCookiesResult = serde_json::from_str(
+            r#"{"value":[{"domain":"example.com","expiry":1700000000,"httpOnly":false,"name":"foo","path":"/","sameSite":"None","secure":true,"value":"bar"}]}"#,
+        )
+        .unwrap();
+        let cookie = &res.cookies()[0];
+        assert_eq!(cookie.name(), "foo");
+        assert_eq!(cookie.value(), "bar");
+        assert_eq!(cookie.domain(), Some("example.com"));
+        assert_eq!(cookie.secure(), Some(true));
+        assert_eq!(cookie.http_only(), Some(false));
+        assert_eq!(cookie.expiry(), Some(1700000000));
+        assert_eq!(cookie.same_site(), Some(SameSite::None));
+
+        let res: GetCookieResult =
+            serde_json::from_str(r#"{"value":{"name":"foo","value":"bar"}}"#).unwrap();
+        assert_eq!(res.cookie(), &Cookie::new("foo", "bar"));
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright (C) 2023 Michael Lee <imichael2e2@proton.me/...@gmail.com>
+//
+// Licensed under the MIT License <LICENSE-MIT or
+// https://opensource.org/license/mit> or the GNU General Public License,
+// Version 3.0 or any later version <LICENSE-GPL or
+// https://www.gnu.org/licenses/gpl-3.0.txt>, at your option.
+//
+// This file may not be copied, modified, or distributed except except in
+// compliance with either of the licenses.
+//
+
+///
+/// Get details of "Get Element Text" command processing result.
+pub trait GetElemTextResultGetter {
+    fn text(&self) -> &str;
+}
+
+///
+/// Get details of "Get Element Attribute" command processing result.
+pub trait GetElemAttrResultGetter {
+    fn attr(&self) -> Option<&str>;
+}
+
+///
+/// Get details of "Get Element Property" command processing result.
+pub trait GetElemPropResultGetter {
+    fn prop(&self) -> &serde_json::Value;
+}
+
+///
+/// Get details of "Get Element CSS Value" command processing result.
+pub trait GetElemCssValueResultGetter {
+    fn css_value(&self) -> &str;
+}
+
+///
+/// Get details of "Get Element Tag Name" command processing result.
+pub trait GetElemTagNameResultGetter {
+    fn tag_name(&self) -> &str;
+}
+
+///
+/// Get details of "Get Element Rect" command processing result.
+pub trait GetElemRectResultGetter {
+    fn rect(&self) -> &ElemRect;
+}
+
+///
+/// Get details of "Is Element Selected/Enabled/Displayed" command
+/// processing result.
+pub trait IsElemStateResultGetter {
+    fn state(&self) -> bool;
+}
+
+///
+/// The "Get Element Text" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetElemTextResult {
+    value: String,
+}
+
+///
+/// The "Get Element Attribute" command processing result.
+///
+/// The value is `null` if the element has no such attribute.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetElemAttrResult {
+    value: Option<String>,
+}
+
+///
+/// The "Get Element Property" command processing result.
+///
+/// A property can be any JSON value, `null` if the element has no such
+/// property.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetElemPropResult {
+    value: serde_json::Value,
+}
+
+///
+/// The "Get Element CSS Value" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetElemCssValueResult {
+    value: String,
+}
+
+///
+/// The "Get Element Tag Name" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetElemTagNameResult {
+    value: String,
+}
+
+///
+/// The "Get Element Rect" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetElemRectResult {
+    value: ElemRect,
+}
+
+///
+/// The "Is Element Selected/Enabled/Displayed" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct IsElemStateResult {
+    value: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct ElemSendKeysParam<'a> {
+    pub(crate) text: &'a str,
+}
+
+///
+/// The position and size of an element, in CSS pixels.
+#[derive(Debug, Default, Clone, Copy, PartialEq, serde::Deserialize)]
+pub struct ElemRect {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+impl ElemRect {
+    #[inline]
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    #[inline]
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    #[inline]
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+}
+
+impl GetElemTextResultGetter for GetElemTextResult {
+    fn text(&self) -> &str {
+        self.value.as_ref()
+    }
+}
+
+impl GetElemAttrResultGetter for GetElemAttrResult {
+    fn attr(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
+
+impl GetElemPropResultGetter for GetElemPropResult {
+    fn prop(&self) -> &serde_json::Value {
+        &self.value
+    }
+}
+
+impl GetElemCssValueResultGetter for GetElemCssValueResult {
+    fn css_value(&self) -> &str {
+        self.value.as_ref()
+    }
+}
+
+impl GetElemTagNameResultGetter for GetElemTagNameResult {
+    fn tag_name(&self) -> &str {
+        self.value.as_ref()
+    }
+}
+
+impl GetElemRectResultGetter for GetElemRectResult {
+    fn rect(&self) -> &ElemRect {
+        &self.value
+    }
+}
+
+impl IsElemStateResultGetter for IsElemStateResult {
+    fn state(&self) -> bool {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod utst {
+    use super::*;
+
+    #[test]
+    fn _1() {
+        let res: GetElemAttrResult = serde_json::from_str(r#"{"value":null}"#).unwrap();
+        assert_eq!(res.attr(), None);
+
+        let res: GetElemAttrResult = serde_json::from_str(r#"{"value":"submit"}"#).unwrap();
+        assert_eq!(res.attr(), Some("submit"));
+    }
+
+    #[test]
+    fn _2() {
+        let res: GetElemRectResult =
+            serde_json::from_str(r#"{"value":{"x":8,"y":21.4333,"width":784,"height":18.0}}"#)
+                .unwrap();
+        let rect = res.rect();
+        assert_eq!(rect.x(), 8.0);
+        assert_eq!(rect.y(), 21.4333);
+        assert_eq!(rect.width(), 784.0);
+        assert_eq!(rect.height(), 18.0);
+    }
+
+    #[test]
+    fn _3() {
+        let res: GetElemPropResult = serde_json::from_str(r#"{"value":3}"#).unwrap();
+        assert_eq!(res.prop(), &serde_json::json!(3));
+
+        let res: IsElemStateResult = serde_json::from_str(r#"{"value":true}"#).unwrap();
+        assert!(res.state());
+    }
+
+    #[test]
+    fn _4() {
+        let actual = serde_json::to_string(&ElemSendKeysParam { text: "a\"b\\c\n" }).unwrap();
+        assert_eq!(actual, r#"{"text":"a\"b\\c\n"}"#);
+
+        // WebDriver special keys are plain characters in the private use area
+        let actual = serde_json::to_string(&ElemSendKeysParam { text: "\u{E007}" }).unwrap();
+        assert_eq!(actual, "{\"text\":\"\u{E007}\"}");
+    }
+}
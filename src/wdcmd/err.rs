@@ -31,6 +31,7 @@ pub struct BadCmdResp<'a> {
 pub struct BadCmdRespDetail<'a> {
     error: Cow<'a, str>,
     message: Cow<'a, str>,
+    data: Option<serde_json::Value>,
     #[cfg(feature = "err_strace")]
     stacktrace: Cow<'a, str>,
 }
@@ -42,6 +43,9 @@ impl BadCmdResp<'_> {
     pub fn msg(&self) -> &str {
         &self.value.message
     }
+    pub fn data(&self) -> Option<&serde_json::Value> {
+        self.value.data.as_ref()
+    }
     #[cfg(feature = "err_strace")]
     pub fn strace(&self) -> &str {
         &self.value.stacktrace
@@ -50,6 +54,277 @@ impl BadCmdResp<'_> {
     // no setters
 }
 
+///
+/// The [error code](https://w3c.github.io/webdriver/#errors) of a failed
+/// command.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WebDriverErrorCode {
+    ///
+    /// The click is received by another element, which obscures the target.
+    ElementClickIntercepted,
+    ///
+    /// The element cannot be interacted with, e.g. it is hidden.
+    ElementNotInteractable,
+    ///
+    /// The navigation hits an expired or invalid TLS certificate.
+    InsecureCertificate,
+    ///
+    /// The arguments passed to a command are invalid or malformed.
+    InvalidArgument,
+    ///
+    /// The cookie is for a domain other than current page's.
+    InvalidCookieDomain,
+    ///
+    /// The element is in a state the command does not allow, e.g. clearing a read-only input.
+    InvalidElementState,
+    ///
+    /// The element selector is invalid.
+    InvalidSelector,
+    ///
+    /// The session does not exist, or is no longer active.
+    InvalidSessionId,
+    ///
+    /// The script throws an error.
+    JavascriptError,
+    ///
+    /// The pointer moves out of the viewport.
+    MoveTargetOutOfBounds,
+    ///
+    /// There is no user prompt open.
+    NoSuchAlert,
+    ///
+    /// There is no cookie of the name.
+    NoSuchCookie,
+    ///
+    /// No element is found by the locator.
+    NoSuchElement,
+    ///
+    /// There is no such frame to switch to.
+    NoSuchFrame,
+    ///
+    /// The window is closed, or there is no such window to switch to.
+    NoSuchWindow,
+    ///
+    /// The element has no shadow root.
+    NoSuchShadowRoot,
+    ///
+    /// The script does not complete before the script timeout.
+    ScriptTimeout,
+    ///
+    /// The session cannot be created.
+    SessionNotCreated,
+    ///
+    /// The element is no longer attached to the document.
+    StaleElementReference,
+    ///
+    /// The shadow root is no longer attached to the document.
+    DetachedShadowRoot,
+    ///
+    /// The command does not complete in time.
+    Timeout,
+    ///
+    /// The cookie cannot be set.
+    UnableToSetCookie,
+    ///
+    /// The screen cannot be captured.
+    UnableToCaptureScreen,
+    ///
+    /// A user prompt blocks the command.
+    UnexpectedAlertOpen,
+    ///
+    /// The server does not recognize the command.
+    UnknownCommand,
+    ///
+    /// An error the server does not classify.
+    UnknownError,
+    ///
+    /// The server recognizes the command, but not its HTTP method.
+    UnknownMethod,
+    ///
+    /// The server cannot perform the operation.
+    UnsupportedOperation,
+    ///
+    /// A code not defined by W3C standard, as is.
+    Other(String),
+}
+
+impl WebDriverErrorCode {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "element click intercepted" => WebDriverErrorCode::ElementClickIntercepted,
+            "element not interactable" => WebDriverErrorCode::ElementNotInteractable,
+            "insecure certificate" => WebDriverErrorCode::InsecureCertificate,
+            "invalid argument" => WebDriverErrorCode::InvalidArgument,
+            "invalid cookie domain" => WebDriverErrorCode::InvalidCookieDomain,
+            "invalid element state" => WebDriverErrorCode::InvalidElementState,
+            "invalid selector" => WebDriverErrorCode::InvalidSelector,
+            "invalid session id" => WebDriverErrorCode::InvalidSessionId,
+            "javascript error" => WebDriverErrorCode::JavascriptError,
+            "move target out of bounds" => WebDriverErrorCode::MoveTargetOutOfBounds,
+            "no such alert" => WebDriverErrorCode::NoSuchAlert,
+            "no such cookie" => WebDriverErrorCode::NoSuchCookie,
+            "no such element" => WebDriverErrorCode::NoSuchElement,
+            "no such frame" => WebDriverErrorCode::NoSuchFrame,
+            "no such window" => WebDriverErrorCode::NoSuchWindow,
+            "no such shadow root" => WebDriverErrorCode::NoSuchShadowRoot,
+            "script timeout" => WebDriverErrorCode::ScriptTimeout,
+            "session not created" => WebDriverErrorCode::SessionNotCreated,
+            "stale element reference" => WebDriverErrorCode::StaleElementReference,
+            "detached shadow root" => WebDriverErrorCode::DetachedShadowRoot,
+            "timeout" => WebDriverErrorCode::Timeout,
+            "unable to set cookie" => WebDriverErrorCode::UnableToSetCookie,
+            "unable to capture screen" => WebDriverErrorCode::UnableToCaptureScreen,
+            "unexpected alert open" => WebDriverErrorCode::UnexpectedAlertOpen,
+            "unknown command" => WebDriverErrorCode::UnknownCommand,
+            "unknown error" => WebDriverErrorCode::UnknownError,
+            "unknown method" => WebDriverErrorCode::UnknownMethod,
+            "unsupported operation" => WebDriverErrorCode::UnsupportedOperation,
+            _ => WebDriverErrorCode::Other(code.to_string()),
+        }
+    }
+
+    ///
+    /// The code as it appears in WebDriver error response.
+    pub fn as_str(&self) -> &str {
+        match self {
+            WebDriverErrorCode::ElementClickIntercepted => "element click intercepted",
+            WebDriverErrorCode::ElementNotInteractable => "element not interactable",
+            WebDriverErrorCode::InsecureCertificate => "insecure certificate",
+            WebDriverErrorCode::InvalidArgument => "invalid argument",
+            WebDriverErrorCode::InvalidCookieDomain => "invalid cookie domain",
+            WebDriverErrorCode::InvalidElementState => "invalid element state",
+            WebDriverErrorCode::InvalidSelector => "invalid selector",
+            WebDriverErrorCode::InvalidSessionId => "invalid session id",
+            WebDriverErrorCode::JavascriptError => "javascript error",
+            WebDriverErrorCode::MoveTargetOutOfBounds => "move target out of bounds",
+            WebDriverErrorCode::NoSuchAlert => "no such alert",
+            WebDriverErrorCode::NoSuchCookie => "no such cookie",
+            WebDriverErrorCode::NoSuchElement => "no such element",
+            WebDriverErrorCode::NoSuchFrame => "no such frame",
+            WebDriverErrorCode::NoSuchWindow => "no such window",
+            WebDriverErrorCode::NoSuchShadowRoot => "no such shadow root",
+            WebDriverErrorCode::ScriptTimeout => "script timeout",
+            WebDriverErrorCode::SessionNotCreated => "session not created",
+            WebDriverErrorCode::StaleElementReference => "stale element reference",
+            WebDriverErrorCode::DetachedShadowRoot => "detached shadow root",
+            WebDriverErrorCode::Timeout => "timeout",
+            WebDriverErrorCode::UnableToSetCookie => "unable to set cookie",
+            WebDriverErrorCode::UnableToCaptureScreen => "unable to capture screen",
+            WebDriverErrorCode::UnexpectedAlertOpen => "unexpected alert open",
+            WebDriverErrorCode::UnknownCommand => "unknown command",
+            WebDriverErrorCode::UnknownError => "unknown error",
+            WebDriverErrorCode::UnknownMethod => "unknown method",
+            WebDriverErrorCode::UnsupportedOperation => "unsupported operation",
+            WebDriverErrorCode::Other(v) => v,
+        }
+    }
+
+    ///
+    /// The HTTP status that W3C standard pairs with the code, 500 if not
+    /// defined.
+    pub fn http_status(&self) -> u16 {
+        match self {
+            WebDriverErrorCode::ElementClickIntercepted
+            | WebDriverErrorCode::ElementNotInteractable
+            | WebDriverErrorCode::InsecureCertificate
+            | WebDriverErrorCode::InvalidArgument
+            | WebDriverErrorCode::InvalidCookieDomain
+            | WebDriverErrorCode::InvalidElementState
+            | WebDriverErrorCode::InvalidSelector => 400,
+            WebDriverErrorCode::InvalidSessionId
+            | WebDriverErrorCode::NoSuchAlert
+            | WebDriverErrorCode::NoSuchCookie
+            | WebDriverErrorCode::NoSuchElement
+            | WebDriverErrorCode::NoSuchFrame
+            | WebDriverErrorCode::NoSuchWindow
+            | WebDriverErrorCode::NoSuchShadowRoot
+            | WebDriverErrorCode::StaleElementReference
+            | WebDriverErrorCode::DetachedShadowRoot
+            | WebDriverErrorCode::UnknownCommand => 404,
+            WebDriverErrorCode::UnknownMethod => 405,
+            _ => 500,
+        }
+    }
+}
+
+impl std::fmt::Display for WebDriverErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+///
+/// A command failure, as reported by WebDriver server.
+///
+/// The client reports some failures itself, before anything is sent, e.g.
+/// `invalid argument`. These have the HTTP status paired with their code,
+/// and neither data nor stacktrace.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebDriverError {
+    code: WebDriverErrorCode,
+    message: String,
+    http_status: u16,
+    data: Option<serde_json::Value>,
+    #[cfg(feature = "err_strace")]
+    stacktrace: String,
+}
+
+impl WebDriverError {
+    pub(crate) fn from_resp(http_status: u16, resp: &BadCmdResp) -> Self {
+        WebDriverError {
+            code: WebDriverErrorCode::from_code(resp.err()),
+            message: resp.msg().to_string(),
+            http_status,
+            data: resp.data().cloned(),
+            #[cfg(feature = "err_strace")]
+            stacktrace: resp.strace().to_string(),
+        }
+    }
+
+    // getter
+
+    #[inline]
+    pub fn code(&self) -> &WebDriverErrorCode {
+        &self.code
+    }
+
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    ///
+    /// The HTTP status of the response.
+    #[inline]
+    pub fn http_status(&self) -> u16 {
+        self.http_status
+    }
+
+    ///
+    /// The additional error data, if the server gives any.
+    #[inline]
+    pub fn data(&self) -> Option<&serde_json::Value> {
+        self.data.as_ref()
+    }
+
+    ///
+    /// The stacktrace, of which the format is up to the server.
+    #[cfg(feature = "err_strace")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "err_strace")))]
+    #[inline]
+    pub fn stacktrace(&self) -> &str {
+        &self.stacktrace
+    }
+}
+
+impl std::fmt::Display for WebDriverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.code, self.message)
+    }
+}
+
+impl std::error::Error for WebDriverError {}
+
 mod deser {
     use super::*;
     use serde::de::{Deserialize, Deserializer, Error as DeError, MapAccess, Visitor};
@@ -189,10 +464,11 @@ mod deser {
             Error,
             Message,
             StackTrace,
+            Data,
             Reserved(String),
         }
 
-        const FIELD_JSON_NAMES: &[&str] = &["error", "message", "stacktrace"];
+        const FIELD_JSON_NAMES: &[&str] = &["error", "message", "stacktrace", "data"];
 
         impl Fields {
             fn from_str(s: &str) -> Self {
@@ -202,6 +478,8 @@ mod deser {
                     Fields::Message
                 } else if s == FIELD_JSON_NAMES[2] {
                     Fields::StackTrace
+                } else if s == FIELD_JSON_NAMES[3] {
+                    Fields::Data
                 } else {
                     Fields::Reserved(s.to_string())
                 }
@@ -211,6 +489,7 @@ mod deser {
                     Fields::Error => FIELD_JSON_NAMES[0],
                     Fields::Message => FIELD_JSON_NAMES[1],
                     Fields::StackTrace => FIELD_JSON_NAMES[2],
+                    Fields::Data => FIELD_JSON_NAMES[3],
                     _ => panic!("unsuppoted operation"),
                 }
             }
@@ -254,6 +533,7 @@ mod deser {
                 // decl!(f1, fid Fields::Message, vtype &'de str);
                 #[cfg(feature = "err_strace")]
                 decl!(f2, fid Fields::StackTrace, vtype String);
+                decl!(f3, fid Fields::Data, vtype serde_json::Value);
 
                 let mut is_dup: Option<A::Error> = None;
 
@@ -272,6 +552,7 @@ mod deser {
                         Fields::Message => give_value_to!(f1),
                         #[cfg(feature = "err_strace")]
                         Fields::StackTrace => give_value_to!(f2),
+                        Fields::Data => give_value_to!(f3),
                         _ => {
                             // skip unknown fields, rather than error
                             use serde::de::IgnoredAny;
@@ -286,8 +567,9 @@ mod deser {
                 } else {
                     let f0 = f0.0.ok_or_else(|| DeError::missing_field(f0.1))?;
                     let f1 = f1.0.ok_or_else(|| DeError::missing_field(f1.1))?;
+                    // some servers leave it out
                     #[cfg(feature = "err_strace")]
-                    let mut f2 = f2.0.ok_or_else(|| DeError::missing_field(f2.1))?;
+                    let f2 = f2.0.unwrap_or_default();
 
                     #[cfg(feature = "err_strace")]
                     let f2_deser_safe = f2.replace(r#"\n"#, "_NL_");
@@ -295,6 +577,7 @@ mod deser {
                     let ret = super::BadCmdRespDetail {
                         error: Cow::from(f0),
                         message: Cow::from(f1),
+                        data: f3.0,
                         #[cfg(feature = "err_strace")]
                         stacktrace: Cow::from(f2_deser_safe),
                     };
@@ -338,6 +621,7 @@ mod deser {
                 value: BadCmdRespDetail {
                     error: Cow::from("a"),
                     message: Cow::from("b"),
+                    data: None,
                 },
             };
 
@@ -377,6 +661,7 @@ mod deser {
                 value: BadCmdRespDetail {
                     error: Cow::from("a"),
                     message: Cow::from("b"),
+                    data: None,
                     stacktrace: Cow::from("c"),
                 },
             };
@@ -415,5 +700,46 @@ mod deser {
                 r#"WebDriverError@chrome://remote/content/shared/webdriver/Errors.jsm:186:5_NL_UnknownError@chrome://remote/content/shared/webdriver/Errors.jsm:513:5_NL_checkReadyState@chrome://remote/content/marionette/navigate.js:65:24_NL_onNavigation@chrome://remote/content/marionette/navigate.js:333:39_NL_emit@resource://gre/modules/EventEmitter.jsm:160:20_NL_receiveMessage@chrome://remote/content/marionette/actors/MarionetteEventsParent.jsm:44:25_NL_"#
             );
         }
+
+        #[test]
+        fn _2() {
+            let estr = r#"{"value":{"error":"unexpected alert open","message":"m","stacktrace":"","data":{"text":"hi"}}}"#;
+            let deobj = serde_json::from_slice::<BadCmdResp>(estr.as_bytes()).unwrap();
+            assert_eq!(deobj.data(), Some(&serde_json::json!({"text": "hi"})));
+
+            let e = WebDriverError::from_resp(500, &deobj);
+            assert_eq!(e.code(), &WebDriverErrorCode::UnexpectedAlertOpen);
+            assert_eq!(e.message(), "m");
+            assert_eq!(e.http_status(), 500);
+            assert_eq!(e.data().unwrap()["text"], "hi");
+            assert_eq!(e.to_string(), "unexpected alert open: m");
+
+            let estr = r#"{"value":{"error":"no such element","message":"","stacktrace":""}}"#;
+            let deobj = serde_json::from_slice::<BadCmdResp>(estr.as_bytes()).unwrap();
+            assert_eq!(deobj.data(), None);
+        }
+
+        #[test]
+        fn _3() {
+            for (code, status) in [
+                ("element click intercepted", 400),
+                ("invalid argument", 400),
+                ("no such shadow root", 404),
+                ("detached shadow root", 404),
+                ("script timeout", 500),
+                ("unknown method", 405),
+                ("unsupported operation", 500),
+            ] {
+                let ecode = WebDriverErrorCode::from_code(code);
+                assert!(!matches!(ecode, WebDriverErrorCode::Other(_)));
+                assert_eq!(ecode.as_str(), code);
+                assert_eq!(ecode.http_status(), status);
+            }
+
+            let ecode = WebDriverErrorCode::from_code("vendor error");
+            assert_eq!(ecode, WebDriverErrorCode::Other("vendor error".to_string()));
+            assert_eq!(ecode.as_str(), "vendor error");
+            assert_eq!(ecode.http_status(), 500);
+        }
     }
 }
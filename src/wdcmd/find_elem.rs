@@ -9,12 +9,37 @@
 // compliance with either of the licenses.
 //
 
-#[derive(Debug, serde::Deserialize)]
-struct WebElem {
+///
+/// A web element reference, as it is represented in JSON.
+///
+/// This is what script results and arguments use for elements. Use
+/// [`WebDrvClient::elem_from_ref`](crate::WebDrvClient::elem_from_ref) to
+/// interact with the element it refers to.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ElemRef {
     #[serde(rename = "element-6066-11e4-a52e-4f735466cecf")]
     webid: String,
 }
 
+impl ElemRef {
+    pub fn new(id: &str) -> Self {
+        ElemRef {
+            webid: id.to_string(),
+        }
+    }
+
+    #[inline]
+    pub fn id(&self) -> &str {
+        &self.webid
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct WebShadowRoot {
+    #[serde(rename = "shadow-6066-11e4-a52e-4f735466cecf")]
+    shadowid: String,
+}
+
 ///
 /// The filter to find elements.
 #[derive(Default, serde::Serialize, Debug)]
@@ -23,6 +48,55 @@ pub struct FindElemFilter {
     value: String,
 }
 
+///
+/// The strategy and its selector used to locate elements.
+///
+/// See the [standard](https://w3c.github.io/webdriver/#locator-strategies)
+/// for the meaning of each strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locator<'a> {
+    Css(&'a str),
+    LinkText(&'a str),
+    PartialLinkText(&'a str),
+    TagName(&'a str),
+    XPath(&'a str),
+}
+
+impl<'a> Locator<'a> {
+    ///
+    /// The strategy keyword, as the "using" field of filter.
+    pub fn using(&self) -> &'static str {
+        match self {
+            Locator::Css(_) => "css selector",
+            Locator::LinkText(_) => "link text",
+            Locator::PartialLinkText(_) => "partial link text",
+            Locator::TagName(_) => "tag name",
+            Locator::XPath(_) => "xpath",
+        }
+    }
+
+    ///
+    /// The selector, as the "value" field of filter.
+    pub fn value(&self) -> &'a str {
+        match self {
+            Locator::Css(v)
+            | Locator::LinkText(v)
+            | Locator::PartialLinkText(v)
+            | Locator::TagName(v)
+            | Locator::XPath(v) => v,
+        }
+    }
+}
+
+impl From<Locator<'_>> for FindElemFilter {
+    fn from(loc: Locator<'_>) -> Self {
+        let mut conf = FindElemFilter::default();
+        conf.set_using(loc.using());
+        conf.set_value(loc.value());
+        conf
+    }
+}
+
 ///
 /// Customize element filter.
 pub trait FindElemFilterSetter {
@@ -42,18 +116,31 @@ pub trait FindElemsResultGetter {
     fn eleids(&self) -> Vec<&str>;
 }
 
+///
+/// Get details of "Get Element Shadow Root" command processing result.
+pub trait GetShadowRootResultGetter {
+    fn shadowid(&self) -> &str;
+}
+
 ///
 /// The "Find Element" command processing result.
 #[derive(Debug, serde::Deserialize)]
 pub struct FindElemResult {
-    value: WebElem,
+    value: ElemRef,
 }
 
 ///
 /// The "Find Elements" command processing result.
 #[derive(Debug, serde::Deserialize)]
 pub struct FindElemsResult {
-    value: Vec<WebElem>,
+    value: Vec<ElemRef>,
+}
+
+///
+/// The "Get Element Shadow Root" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetShadowRootResult {
+    value: WebShadowRoot,
 }
 
 impl FindElemFilterSetter for FindElemFilter {
@@ -76,3 +163,65 @@ impl FindElemsResultGetter for FindElemsResult {
         self.value.iter().map(|x| x.webid.as_ref()).collect()
     }
 }
+
+impl GetShadowRootResultGetter for GetShadowRootResult {
+    fn shadowid(&self) -> &str {
+        self.value.shadowid.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod utst {
+    use super::*;
+
+    #[test]
+    fn _1() {
+        let conf = FindElemFilter::from(Locator::XPath("//a[@id='x']"));
+        assert_eq!(
+            serde_json::to_string(&conf).unwrap(),
+            r#"{"using":"xpath","value":"//a[@id='x']"}"#
+        );
+
+        let conf = FindElemFilter::from(Locator::PartialLinkText("more"));
+        assert_eq!(
+            serde_json::to_string(&conf).unwrap(),
+            r#"{"using":"partial link text","value":"more"}"#
+        );
+    }
+
+    #[test]
+    fn _2() {
+        let res: GetShadowRootResult =
+            serde_json::from_str(r#"{"value":{"shadow-6066-11e4-a52e-4f735466cecf":"c6b0-4c5e"}}"#)
+                .unwrap();
+        assert_eq!(res.shadowid(), "c6b0-4c5e");
+
+        let res = serde_json::from_str::<GetShadowRootResult>(
+            r#"{"value":{"element-6066-11e4-a52e-4f735466cecf":"c6b0-4c5e"}}"#,
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn _3() {
+        let res: FindElemResult = serde_json::from_str(
+            r#"{"value":{"element-6066-11e4-a52e-4f735466cecf":"6f2c-77e1"}}"#,
+        )
+        .unwrap();
+        assert_eq!(res.eleid(), "6f2c-77e1");
+
+        let res: FindElemsResult = serde_json::from_str(
+            r#"{"value":[{"element-6066-11e4-a52e-4f735466cecf":"a1"},{"element-6066-11e4-a52e-4f735466cecf":"b2"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(res.eleids(), vec!["a1", "b2"]);
+    }
+
+    #[test]
+    fn _4() {
+        let elem = ElemRef::new("6f2c-77e1");
+        let s = serde_json::to_string(&elem).unwrap();
+        assert_eq!(s, r#"{"element-6066-11e4-a52e-4f735466cecf":"6f2c-77e1"}"#);
+        assert_eq!(serde_json::from_str::<ElemRef>(&s).unwrap(), elem);
+    }
+}
@@ -9,6 +9,11 @@
 // compliance with either of the licenses.
 //
 
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct NaviToParam<'a> {
+    pub(crate) url: &'a str,
+}
+
 ///
 /// Get details of “Get Current Url” command processing result.
 pub trait GetUrlResultGetter {
@@ -27,3 +32,36 @@ impl GetUrlResultGetter for GetUrlResult {
         self.value.as_ref()
     }
 }
+
+///
+/// Get details of "Get Title" command processing result.
+pub trait GetTitleResultGetter {
+    fn title(&self) -> &str;
+}
+
+///
+/// The "Get Title" command processing result.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct GetTitleResult {
+    value: String,
+}
+
+impl GetTitleResultGetter for GetTitleResult {
+    fn title(&self) -> &str {
+        self.value.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod utst {
+    use super::*;
+
+    #[test]
+    fn _1() {
+        let actual = serde_json::to_string(&NaviToParam {
+            url: r#"data:text/html,<p id="x">a\b</p>"#,
+        })
+        .unwrap();
+        assert_eq!(actual, r#"{"url":"data:text/html,<p id=\"x\">a\\b</p>"}"#);
+    }
+}
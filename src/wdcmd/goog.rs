@@ -0,0 +1,263 @@
+// Copyright (C) 2023 Michael Lee <imichael2e2@proton.me/...@gmail.com>
+//
+// Licensed under the MIT License <LICENSE-MIT or
+// https://opensource.org/license/mit> or the GNU General Public License,
+// Version 3.0 or any later version <LICENSE-GPL or
+// https://www.gnu.org/licenses/gpl-3.0.txt>, at your option.
+//
+// This file may not be copied, modified, or distributed except except in
+// compliance with either of the licenses.
+//
+
+///
+/// The network conditions ChromeDriver emulates.
+///
+/// Latency is in milliseconds, throughputs are in bytes per second.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct NetworkConditions {
+    offline: bool,
+    latency: f64,
+    download_throughput: f64,
+    upload_throughput: f64,
+}
+
+impl NetworkConditions {
+    pub fn new(latency: f64, download_throughput: f64, upload_throughput: f64) -> Self {
+        NetworkConditions {
+            offline: false,
+            latency,
+            download_throughput,
+            upload_throughput,
+        }
+    }
+
+    // getter
+
+    #[inline]
+    pub fn offline(&self) -> bool {
+        self.offline
+    }
+
+    #[inline]
+    pub fn latency(&self) -> f64 {
+        self.latency
+    }
+
+    #[inline]
+    pub fn download_throughput(&self) -> f64 {
+        self.download_throughput
+    }
+
+    #[inline]
+    pub fn upload_throughput(&self) -> f64 {
+        self.upload_throughput
+    }
+
+    // setter
+
+    pub fn set_offline(&mut self, arg: bool) -> &mut Self {
+        self.offline = arg;
+        self
+    }
+
+    pub fn set_latency(&mut self, arg: f64) -> &mut Self {
+        self.latency = arg;
+        self
+    }
+
+    pub fn set_download_throughput(&mut self, arg: f64) -> &mut Self {
+        self.download_throughput = arg;
+        self
+    }
+
+    pub fn set_upload_throughput(&mut self, arg: f64) -> &mut Self {
+        self.upload_throughput = arg;
+        self
+    }
+}
+
+///
+/// A log entry collected by ChromeDriver.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+pub struct LogEntry {
+    level: String,
+    message: String,
+    #[serde(default)]
+    source: Option<String>,
+    timestamp: f64,
+}
+
+impl LogEntry {
+    ///
+    /// The level, e.g. "SEVERE", "WARNING" or "INFO".
+    #[inline]
+    pub fn level(&self) -> &str {
+        &self.level
+    }
+
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    ///
+    /// The source, e.g. "console-api" or "network", if reported.
+    #[inline]
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    ///
+    /// The time the entry is logged, in milliseconds since Unix Epoch.
+    #[inline]
+    pub fn timestamp(&self) -> f64 {
+        self.timestamp
+    }
+}
+
+///
+/// Get details of "Get Network Conditions" command processing result.
+pub trait GetNetworkConditionsResultGetter {
+    fn network_conditions(&self) -> &NetworkConditions;
+}
+
+///
+/// Get details of "Get Log" command processing result.
+pub trait GetLogResultGetter {
+    fn entries(&self) -> &[LogEntry];
+}
+
+///
+/// Get details of "Get Log Types" command processing result.
+pub trait GetLogTypesResultGetter {
+    fn log_types(&self) -> &[String];
+}
+
+///
+/// The "Get Network Conditions" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetNetworkConditionsResult {
+    value: NetworkConditions,
+}
+
+///
+/// The "Get Log" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetLogResult {
+    value: Vec<LogEntry>,
+}
+
+///
+/// The "Get Log Types" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetLogTypesResult {
+    value: Vec<String>,
+}
+
+///
+/// The "Execute CDP Command" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct ExecCdpResult<T> {
+    pub(crate) value: T,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct ExecCdpParam<'a> {
+    pub(crate) cmd: &'a str,
+    pub(crate) params: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct SetNetworkConditionsParam<'a> {
+    pub(crate) network_conditions: &'a NetworkConditions,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct GetLogParam<'a> {
+    #[serde(rename = "type")]
+    pub(crate) log_type: &'a str,
+}
+
+impl GetNetworkConditionsResultGetter for GetNetworkConditionsResult {
+    fn network_conditions(&self) -> &NetworkConditions {
+        &self.value
+    }
+}
+
+impl GetLogResultGetter for GetLogResult {
+    fn entries(&self) -> &[LogEntry] {
+        &self.value
+    }
+}
+
+impl GetLogTypesResultGetter for GetLogTypesResult {
+    fn log_types(&self) -> &[String] {
+        &self.value
+    }
+}
+
+#[cfg(test)]
+mod utst {
+    use super::*;
+
+    #[test]
+    fn _1() {
+        let mut params = serde_json::Map::new();
+        params.insert("expression".to_string(), serde_json::json!("1 + 1"));
+        let actual = serde_json::to_string(&ExecCdpParam {
+            cmd: "Runtime.evaluate",
+            params,
+        })
+        .unwrap();
+        assert_eq!(
+            actual,
+            r#"{"cmd":"Runtime.evaluate","params":{"expression":"1 + 1"}}"#
+        );
+
+        let res: ExecCdpResult<serde_json::Value> =
+            serde_json::from_str(r#"{"value":{"result":{"type":"number","value":2}}}"#).unwrap();
+        assert_eq!(res.value["result"]["value"], 2);
+    }
+
+    #[test]
+    fn _2() {
+        let mut conds = NetworkConditions::new(5.0, 500.0 * 1024.0, 100.0 * 1024.0);
+        conds.set_offline(true);
+        let actual = serde_json::to_string(&SetNetworkConditionsParam {
+            network_conditions: &conds,
+        })
+        .unwrap();
+        assert_eq!(
+            actual,
+            r#"{"network_conditions":{"offline":true,"latency":5.0,"download_throughput":512000.0,"upload_throughput":102400.0}}"#
+        );
+
+        let res: GetNetworkConditionsResult = serde_json::from_str(
+            r#"{"value":{"download_throughput":512000,"latency":5,"offline":true,"upload_throughput":102400}}"#,
+        )
+        .unwrap();
+        assert_eq!(res.network_conditions(), &conds);
+    }
+
+    #[test]
+    fn _3() {
+        let actual = serde_json::to_string(&GetLogParam {
+            log_type: "browser",
+        })
+        .unwrap();
+        assert_eq!(actual, r#"{"type":"browser"}"#);
+
+        let res: GetLogResult = serde_json::from_str(
+            r#"{"value":[{"level":"SEVERE","message":"console-api 1:9 \"wdc\"","source":"console-api","timestamp":1700000000000.5}]}"#,
+        )
+        .unwrap();
+        let entry = &res.entries()[0];
+        assert_eq!(entry.level(), "SEVERE");
+        assert_eq!(entry.source(), Some("console-api"));
+        assert_eq!(entry.timestamp(), 1700000000000.5);
+
+        let res: GetLogTypesResult =
+            serde_json::from_str(r#"{"value":["browser","driver"]}"#).unwrap();
+        assert_eq!(res.log_types(), ["browser", "driver"]);
+    }
+}
@@ -42,13 +42,57 @@ pub mod session;
 pub mod status;
 
 ///
-/// Webdriver "Navigate To" command.
+/// Webdriver "Navigation" commands.
 pub mod get_url;
 
+///
+/// Webdriver "Get/Set Timeouts" commands.
+pub mod timeouts;
+
 ///
 /// Webdriver "Find Element(s)" command.
 pub mod find_elem;
 
 ///
-/// Webdriver "Perform Actions" command.
+/// Webdriver "Cookies" commands.
+pub mod cookie;
+
+///
+/// Webdriver "User Prompts" commands.
+pub mod alert;
+
+///
+/// Webdriver "Perform/Release Actions" commands.
 pub mod actions;
+
+///
+/// Webdriver "Element State/Interaction" commands.
+pub mod elem;
+
+///
+/// Webdriver "Contexts" commands.
+pub mod window;
+
+///
+/// Webdriver "Take (Element) Screenshot" commands.
+pub mod screenshot;
+
+///
+/// Webdriver "Print Page" command.
+pub mod print;
+
+///
+/// Webdriver "Execute Script" commands.
+pub mod script;
+
+///
+/// GeckoDriver-specific commands.
+#[cfg(feature = "firefox")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "firefox")))]
+pub mod moz;
+
+///
+/// ChromeDriver-specific commands.
+#[cfg(feature = "chromium")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "chromium")))]
+pub mod goog;
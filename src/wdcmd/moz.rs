@@ -0,0 +1,114 @@
+// Copyright (C) 2023 Michael Lee <imichael2e2@proton.me/...@gmail.com>
+//
+// Licensed under the MIT License <LICENSE-MIT or
+// https://opensource.org/license/mit> or the GNU General Public License,
+// Version 3.0 or any later version <LICENSE-GPL or
+// https://www.gnu.org/licenses/gpl-3.0.txt>, at your option.
+//
+// This file may not be copied, modified, or distributed except except in
+// compliance with either of the licenses.
+//
+
+///
+/// The context of GeckoDriver commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GeckoContext {
+    ///
+    /// Web content, the default.
+    Content,
+    ///
+    /// Browser chrome, where privileged scripts run.
+    Chrome,
+}
+
+///
+/// Get details of "Get Context" command processing result.
+pub trait GetContextResultGetter {
+    fn context(&self) -> GeckoContext;
+}
+
+///
+/// Get details of "Install Addon" command processing result.
+pub trait InstallAddonResultGetter {
+    fn addon_id(&self) -> &str;
+}
+
+///
+/// The "Get Context" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetContextResult {
+    value: GeckoContext,
+}
+
+///
+/// The "Install Addon" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct InstallAddonResult {
+    value: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct SetContextParam {
+    pub(crate) context: GeckoContext,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct InstallAddonParam<'a> {
+    pub(crate) addon: &'a str,
+    pub(crate) temporary: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct UninstallAddonParam<'a> {
+    pub(crate) id: &'a str,
+}
+
+impl GetContextResultGetter for GetContextResult {
+    fn context(&self) -> GeckoContext {
+        self.value
+    }
+}
+
+impl InstallAddonResultGetter for InstallAddonResult {
+    fn addon_id(&self) -> &str {
+        &self.value
+    }
+}
+
+#[cfg(test)]
+mod utst {
+    use super::*;
+
+    #[test]
+    fn _1() {
+        let res: GetContextResult = serde_json::from_str(r#"{"value":"chrome"}"#).unwrap();
+        assert_eq!(res.context(), GeckoContext::Chrome);
+
+        let actual = serde_json::to_string(&SetContextParam {
+            context: GeckoContext::Content,
+        })
+        .unwrap();
+        assert_eq!(actual, r#"{"context":"content"}"#);
+    }
+
+    #[test]
+    fn _2() {
+        let actual = serde_json::to_string(&InstallAddonParam {
+            addon: "UEsFBg==",
+            temporary: true,
+        })
+        .unwrap();
+        assert_eq!(actual, r#"{"addon":"UEsFBg==","temporary":true}"#);
+
+        let res: InstallAddonResult =
+            serde_json::from_str(r#"{"value":"wdc-test@example.org"}"#).unwrap();
+        assert_eq!(res.addon_id(), "wdc-test@example.org");
+
+        let actual = serde_json::to_string(&UninstallAddonParam {
+            id: "wdc-test@example.org",
+        })
+        .unwrap();
+        assert_eq!(actual, r#"{"id":"wdc-test@example.org"}"#);
+    }
+}
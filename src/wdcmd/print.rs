@@ -0,0 +1,169 @@
+// Copyright (C) 2023 Michael Lee <imichael2e2@proton.me/...@gmail.com>
+//
+// Licensed under the MIT License <LICENSE-MIT or
+// https://opensource.org/license/mit> or the GNU General Public License,
+// Version 3.0 or any later version <LICENSE-GPL or
+// https://www.gnu.org/licenses/gpl-3.0.txt>, at your option.
+//
+// This file may not be copied, modified, or distributed except except in
+// compliance with either of the licenses.
+//
+
+///
+/// The orientation of printed pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Orientation {
+    Portrait,
+    Landscape,
+}
+
+///
+/// A page, or an inclusive range of pages, to be printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageRange {
+    Page(u32),
+    Range(u32, u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+struct PageSize {
+    width: f64,
+    height: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+struct PageMargin {
+    top: f64,
+    bottom: f64,
+    left: f64,
+    right: f64,
+}
+
+///
+/// The parameters of [Print Page](https://w3c.github.io/webdriver/#print-page)
+/// command. Lengths are in centimeters.
+///
+/// Unset parameters are left to WebDriver server, except that backgrounds
+/// are printed and the orientation is portrait by default.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    orientation: Option<Orientation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scale: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    background: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<PageSize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    margin: Option<PageMargin>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shrink_to_fit: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    page_ranges: Vec<PageRange>,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        PrintOptions {
+            orientation: Some(Orientation::Portrait),
+            scale: None,
+            background: Some(true),
+            page: None,
+            margin: None,
+            shrink_to_fit: None,
+            page_ranges: vec![],
+        }
+    }
+}
+
+impl PrintOptions {
+    pub fn set_orientation(&mut self, arg: Orientation) -> &mut Self {
+        self.orientation = Some(arg);
+        self
+    }
+
+    ///
+    /// Set the scale, between 0.1 and 2.
+    pub fn set_scale(&mut self, arg: f64) -> &mut Self {
+        self.scale = Some(arg);
+        self
+    }
+
+    pub fn set_background(&mut self, arg: bool) -> &mut Self {
+        self.background = Some(arg);
+        self
+    }
+
+    pub fn set_page_size(&mut self, width: f64, height: f64) -> &mut Self {
+        self.page = Some(PageSize { width, height });
+        self
+    }
+
+    pub fn set_margin(&mut self, top: f64, bottom: f64, left: f64, right: f64) -> &mut Self {
+        self.margin = Some(PageMargin {
+            top,
+            bottom,
+            left,
+            right,
+        });
+        self
+    }
+
+    pub fn set_shrink_to_fit(&mut self, arg: bool) -> &mut Self {
+        self.shrink_to_fit = Some(arg);
+        self
+    }
+
+    ///
+    /// Add pages to be printed. All pages are printed if none is added.
+    pub fn add_page_range(&mut self, arg: PageRange) -> &mut Self {
+        self.page_ranges.push(arg);
+        self
+    }
+}
+
+mod ser {
+    use super::*;
+    use serde::ser::{Serialize, Serializer};
+
+    impl Serialize for PageRange {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                PageRange::Page(v) => serializer.serialize_u32(*v),
+                PageRange::Range(from, to) => serializer.serialize_str(&format!("{}-{}", from, to)),
+            }
+        }
+    }
+} // ser
+
+#[cfg(test)]
+mod utst {
+    use super::*;
+
+    #[test]
+    fn _1() {
+        let actual = serde_json::to_string(&PrintOptions::default()).unwrap();
+        assert_eq!(actual, r#"{"orientation":"portrait","background":true}"#);
+    }
+
+    #[test]
+    fn _2() {
+        let mut opts = PrintOptions::default();
+        opts.set_orientation(Orientation::Landscape)
+            .set_scale(0.5)
+            .set_background(false)
+            .set_page_size(21.0, 29.7)
+            .set_margin(1.0, 1.0, 2.0, 2.0)
+            .set_shrink_to_fit(false)
+            .add_page_range(PageRange::Page(1))
+            .add_page_range(PageRange::Range(3, 5));
+        let actual = serde_json::to_string(&opts).unwrap();
+        assert_eq!(
+            actual,
+            r#"{"orientation":"landscape","scale":0.5,"background":false,"page":{"width":21.0,"height":29.7},"margin":{"top":1.0,"bottom":1.0,"left":2.0,"right":2.0},"shrinkToFit":false,"pageRanges":[1,"3-5"]}"#
+        );
+    }
+}
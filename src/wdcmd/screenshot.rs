@@ -0,0 +1,52 @@
+// Copyright (C) 2023 Michael Lee <imichael2e2@proton.me/...@gmail.com>
+//
+// Licensed under the MIT License <LICENSE-MIT or
+// https://opensource.org/license/mit> or the GNU General Public License,
+// Version 3.0 or any later version <LICENSE-GPL or
+// https://www.gnu.org/licenses/gpl-3.0.txt>, at your option.
+//
+// This file may not be copied, modified, or distributed except except in
+// compliance with either of the licenses.
+//
+
+const PNG_SIGNATURE: &[u8; 8] = b"\x89PNG\r\n\x1a\n";
+
+///
+/// Get the width and height of a PNG image, in pixels.
+///
+/// They are read from the IHDR chunk, which always comes first, so `png`
+/// can be a prefix of the whole image as long as it covers that chunk.
+/// `None` is returned if `png` does not start with a valid IHDR chunk.
+pub fn png_size(png: &[u8]) -> Option<(u32, u32)> {
+    // signature(8) + length(4) + type(4) + width(4) + height(4)
+    if png.len() < 24 || &png[0..8] != PNG_SIGNATURE || &png[12..16] != b"IHDR" {
+        return None;
+    }
+
+    let width = u32::from_be_bytes([png[16], png[17], png[18], png[19]]);
+    let height = u32::from_be_bytes([png[20], png[21], png[22], png[23]]);
+
+    Some((width, height))
+}
+
+#[cfg(test)]
+mod utst {
+    use super::*;
+
+    #[test]
+    fn _1() {
+        let mut png = Vec::<u8>::new();
+        png.extend(PNG_SIGNATURE);
+        png.extend([0, 0, 0, 13]);
+        png.extend(b"IHDR");
+        png.extend(1280u32.to_be_bytes());
+        png.extend(720u32.to_be_bytes());
+        png.extend([8, 6, 0, 0, 0]);
+        assert_eq!(png_size(&png), Some((1280, 720)));
+
+        assert_eq!(png_size(&png[0..20]), None);
+        png[12] = b'i';
+        assert_eq!(png_size(&png), None);
+        assert_eq!(png_size(b"%PDF-1.5"), None);
+    }
+}
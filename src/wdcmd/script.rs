@@ -0,0 +1,66 @@
+// Copyright (C) 2023 Michael Lee <imichael2e2@proton.me/...@gmail.com>
+//
+// Licensed under the MIT License <LICENSE-MIT or
+// https://opensource.org/license/mit> or the GNU General Public License,
+// Version 3.0 or any later version <LICENSE-GPL or
+// https://www.gnu.org/licenses/gpl-3.0.txt>, at your option.
+//
+// This file may not be copied, modified, or distributed except except in
+// compliance with either of the licenses.
+//
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct ExecScriptParam<'a> {
+    pub(crate) script: &'a str,
+    pub(crate) args: Vec<serde_json::Value>,
+}
+
+///
+/// The "Execute Script" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct ExecScriptResult<T> {
+    pub(crate) value: T,
+}
+
+#[cfg(test)]
+mod utst {
+    use super::*;
+
+    #[test]
+    fn _1() {
+        let actual = serde_json::to_string(&ExecScriptParam {
+            script: r#"return "a\b" + 'c' + "\n";"#,
+            args: vec![],
+        })
+        .unwrap();
+        assert_eq!(
+            actual,
+            r#"{"script":"return \"a\\b\" + 'c' + \"\\n\";","args":[]}"#
+        );
+
+        let actual = serde_json::to_string(&ExecScriptParam {
+            script: "return arguments[0];",
+            args: vec![serde_json::json!(3), serde_json::json!("x\"y")],
+        })
+        .unwrap();
+        assert_eq!(
+            actual,
+            r#"{"script":"return arguments[0];","args":[3,"x\"y"]}"#
+        );
+    }
+
+    #[test]
+    fn _2() {
+        let res: ExecScriptResult<Vec<String>> =
+            serde_json::from_str(r#"{"value":["a","b\"c"]}"#).unwrap();
+        assert_eq!(res.value, vec!["a", "b\"c"]);
+
+        let res: ExecScriptResult<Vec<crate::wdcmd::find_elem::ElemRef>> =
+            serde_json::from_str(r#"{"value":[{"element-6066-11e4-a52e-4f735466cecf":"a1"}]}"#)
+                .unwrap();
+        assert_eq!(res.value[0].id(), "a1");
+
+        let res: Result<ExecScriptResult<u32>, _> = serde_json::from_str(r#"{"value":"x"}"#);
+        assert!(res.is_err());
+    }
+}
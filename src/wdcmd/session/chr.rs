@@ -10,6 +10,7 @@
 //
 
 pub mod any {
+    use super::super::comm::PrefValue;
     use super::super::w3c;
     use super::any as chrom;
 
@@ -38,7 +39,7 @@ pub mod any {
         fn extensions(&self) -> Option<Vec<&str>> {
             None
         }
-        fn prefs(&self) -> Option<BTreeMap<&str, &str>> {
+        fn prefs(&self) -> Option<BTreeMap<&str, &PrefValue<'_>>> {
             None
         }
         fn detach(&self) -> Option<bool> {
@@ -84,9 +85,9 @@ pub mod any {
         #[allow(unused_variables)]
         fn add_extensions(&mut self, arg: &'c str) {}
         #[allow(unused_variables)]
-        fn set_prefs(&mut self, arg: BTreeMap<&'c str, &'c str>) {}
+        fn set_prefs(&mut self, arg: BTreeMap<&'c str, PrefValue<'c>>) {}
         #[allow(unused_variables)]
-        fn add_prefs(&mut self, key: &'c str, value: &'c str) {}
+        fn add_prefs(&mut self, key: &'c str, value: impl Into<PrefValue<'c>>) {}
         #[allow(unused_variables)]
         fn set_detach(&mut self, arg: bool) {}
         #[allow(unused_variables)]
@@ -163,7 +164,7 @@ use super::w3c;
 use any as chrom;
 
 use chrom::{ChromiumCapaGetter, ChromiumCapaSetter, ChromiumSesAlnCap};
-use comm::VarValTypeMap;
+use comm::PrefValue;
 use w3c::{W3cCapaGetter, W3cCapaSetter};
 
 use std::borrow::Cow;
@@ -174,7 +175,7 @@ pub struct ChromiumSesExtCap<'c> {
     args: Option<Vec<Cow<'c, str>>>,
     binary: Option<Cow<'c, str>>,
     extensions: Option<Vec<Cow<'c, str>>>,
-    prefs: Option<VarValTypeMap<Cow<'c, str>, Cow<'c, str>>>,
+    prefs: Option<BTreeMap<Cow<'c, str>, PrefValue<'c>>>,
     detach: Option<bool>,
     debugger_address: Option<Cow<'c, str>>,
     exclude_switches: Option<Vec<Cow<'c, str>>>,
@@ -552,7 +553,7 @@ where
             newme.set_extensions(v);
         }
         if let Some(v) = other.prefs() {
-            newme.set_prefs(v);
+            newme.set_prefs(v.into_iter().map(|(k, v)| (k, v.clone())).collect());
         }
         if let Some(v) = other.detach() {
             newme.set_detach(v);
@@ -629,15 +630,10 @@ impl chrom::ChromiumCapaGetter for ChromiumCapa<'_> {
             Some(v) => Some(v.iter().map(|x| x.as_ref()).collect()),
         }
     }
-    fn prefs(&self) -> Option<BTreeMap<&str, &str>> {
+    fn prefs(&self) -> Option<BTreeMap<&str, &PrefValue<'_>>> {
         match self.ext.prefs.as_ref() {
             None => None,
-            Some(v) => Some(
-                v.map
-                    .iter()
-                    .map(|(k, v)| (k.as_ref(), v.as_ref()))
-                    .collect(),
-            ),
+            Some(v) => Some(v.iter().map(|(k, v)| (k.as_ref(), v)).collect()),
         }
     }
     fn detach(&self) -> Option<bool> {
@@ -698,24 +694,18 @@ where
             None => self.set_args(vec![arg]),
         }
     }
-    fn set_prefs(&mut self, arg: BTreeMap<&'c str, &'c str>) {
-        let mut newone = VarValTypeMap::<Cow<'c, str>, Cow<'c, str>>::default();
-        for (key, value) in arg.iter() {
-            newone.map.insert(Cow::from(*key), Cow::from(*value));
-        }
-        self.ext.prefs = Some(newone);
+    fn set_prefs(&mut self, arg: BTreeMap<&'c str, PrefValue<'c>>) {
+        self.ext.prefs = Some(
+            arg.into_iter()
+                .map(|(key, value)| (Cow::from(key), value))
+                .collect(),
+        );
     }
-    fn add_prefs(&mut self, key: &'c str, value: &'c str) {
-        match self.ext.prefs.as_mut() {
-            Some(v) => {
-                v.map.insert(Cow::from(key), Cow::from(value));
-            }
-            None => {
-                let mut newone = BTreeMap::<&str, &str>::default();
-                newone.insert(key, value);
-                self.set_prefs(newone);
-            }
-        }
+    fn add_prefs(&mut self, key: &'c str, value: impl Into<PrefValue<'c>>) {
+        self.ext
+            .prefs
+            .get_or_insert_with(BTreeMap::new)
+            .insert(Cow::from(key), value.into());
     }
     fn set_detach(&mut self, arg: bool) {
         self.ext.detach = Some(arg);
@@ -946,6 +936,12 @@ mod ser {
             if let Some(v) = self.strict_file_interactability() {
                 state.serialize_field("strictFileInteractability", &v)?;
             }
+            if let Some(v) = self.window_rect() {
+                state.serialize_field("setWindowRect", &v)?;
+            }
+            if let Some(v) = self.unhandled_prompt_behavior() {
+                state.serialize_field("unhandledPromptBehavior", &v)?;
+            }
             if let Some(v) = &self.timeouts {
                 state.serialize_field("timeouts", v)?;
             }
@@ -1253,9 +1249,7 @@ mod deser {
                     ret.set_page_load_strategy(f4);
                     ret.proxy = Some(f5);
                     ret.set_window_rect(f6);
-                    ret.set_timeouts_script(f7.script);
-                    ret.set_timeouts_page_load(f7.page_load);
-                    ret.set_timeouts_implicit(f7.implicit);
+                    ret.timeouts = Some(f7);
                     ret.set_strict_file_interactability(f8);
                     ret.set_unhandled_prompt_behavior(f9);
 
@@ -1382,9 +1376,7 @@ mod deser {
                     ret.set_page_load_strategy_take(f4);
                     ret.proxy = Some(f5);
                     ret.set_window_rect(f6);
-                    ret.set_timeouts_script(f7.script);
-                    ret.set_timeouts_page_load(f7.page_load);
-                    ret.set_timeouts_implicit(f7.implicit);
+                    ret.timeouts = Some(f7);
                     ret.set_strict_file_interactability(f8);
                     ret.set_unhandled_prompt_behavior_take(f9);
 
@@ -2307,6 +2299,7 @@ mod utst {
                         len: 3,
                     },
                     Token::Str("script"),
+                    Token::Some,
                     Token::U32(1234),
                     Token::Str("pageLoad"),
                     Token::U32(300_000),
@@ -2355,6 +2348,7 @@ mod utst {
                         len: 3,
                     },
                     Token::Str("script"),
+                    Token::Some,
                     Token::U32(1234),
                     Token::Str("pageLoad"),
                     Token::U32(300_000),
@@ -2600,7 +2594,7 @@ mod utst {
             capa.set_browser_name("chrome");
             capa.set_binary("/path/to/chrome");
             capa.add_prefs("profile.name", "A Real Human");
-            capa.add_prefs("profile.avatar_index", "16");
+            capa.add_prefs("profile.avatar_index", 16);
 
             requ.mandate(&capa);
 
@@ -2620,7 +2614,7 @@ mod utst {
                     Token::Str("prefs"),
                     Token::Map { len: Some(2) },
                     Token::Str("profile.avatar_index"), // backing is type of order map
-                    Token::U32(16),
+                    Token::I64(16),
                     Token::Str("profile.name"),
                     Token::Str("A Real Human"),
                     Token::MapEnd,
@@ -2649,9 +2643,9 @@ mod utst {
             capa.set_proxy_type("manual");
             capa.set_socks_version(5);
             capa.set_socks_proxy("127.0.0.1:1080");
-            capa.add_prefs("browser.check_default_browser", "false");
-            capa.add_prefs("dns_prefetching.enabled", "true");
-            capa.add_prefs("profile.default_content_settings.geolocation", "0");
+            capa.add_prefs("browser.check_default_browser", false);
+            capa.add_prefs("dns_prefetching.enabled", true);
+            capa.add_prefs("profile.default_content_settings.geolocation", 0);
             requ.mandate(&capa);
 
             // token stream is correct?
@@ -2682,7 +2676,7 @@ mod utst {
                     Token::Str("dns_prefetching.enabled"),
                     Token::Bool(true),
                     Token::Str("profile.default_content_settings.geolocation"),
-                    Token::U32(0),
+                    Token::I64(0),
                     Token::MapEnd,
                     Token::StructEnd,
                     Token::StructEnd,
@@ -25,14 +25,59 @@ pub(crate) struct Proxy<'c> {
     pub(crate) socks_version: u8,
 }
 
-#[derive(Debug, serde::Serialize)]
-pub(crate) struct Timeouts {
-    pub(crate) script: u32,
+///
+/// The session [timeouts](https://w3c.github.io/webdriver/#timeouts), in
+/// milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct Timeouts {
+    pub(crate) script: Option<u32>,
     #[serde(rename = "pageLoad")]
     pub(crate) page_load: u32,
     pub(crate) implicit: u32,
 }
 
+impl Timeouts {
+    // getter
+
+    ///
+    /// Timeout for script evaluation, `None` means scripts never time out.
+    #[inline]
+    pub fn script(&self) -> Option<u32> {
+        self.script
+    }
+
+    ///
+    /// Timeout for page loading.
+    #[inline]
+    pub fn page_load(&self) -> u32 {
+        self.page_load
+    }
+
+    ///
+    /// Timeout for implicit waiting of element location.
+    #[inline]
+    pub fn implicit(&self) -> u32 {
+        self.implicit
+    }
+
+    // setter
+
+    pub fn set_script(&mut self, arg: Option<u32>) -> &mut Self {
+        self.script = arg;
+        self
+    }
+
+    pub fn set_page_load(&mut self, arg: u32) -> &mut Self {
+        self.page_load = arg;
+        self
+    }
+
+    pub fn set_implicit(&mut self, arg: u32) -> &mut Self {
+        self.implicit = arg;
+        self
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct NoAlien;
 
@@ -155,7 +200,7 @@ impl<X: Default, A: Default, L: Default> w3c::W3cCapaGetter for CommCapa<'_, X,
     fn timeouts_script(&self) -> Option<u32> {
         match self.timeouts.as_ref() {
             None => None,
-            Some(v) => Some(v.script),
+            Some(v) => v.script,
         }
     }
     fn timeouts_page_load(&self) -> Option<u32> {
@@ -393,12 +438,12 @@ where
         match self.timeouts.as_mut() {
             None => {
                 let newone = Timeouts {
-                    script: arg,
+                    script: Some(arg),
                     ..Default::default()
                 };
                 self.timeouts = Some(newone);
             }
-            Some(v) => v.script = arg,
+            Some(v) => v.script = Some(arg),
         }
     }
     fn set_timeouts_page_load(&mut self, arg: u32) {
@@ -508,7 +553,7 @@ where
 impl Default for Timeouts {
     fn default() -> Self {
         Timeouts {
-            script: 30_000,
+            script: Some(30_000),
             page_load: 300_000,
             implicit: 0,
         }
@@ -526,20 +571,72 @@ where
     fn wsurl(&self) -> Option<&str> {
         self.value.capabilities.wsurl()
     }
+
+    fn window_rect(&self) -> Option<bool> {
+        self.value.capabilities.window_rect()
+    }
+
+    fn unhandled_prompt_behavior(&self) -> Option<&str> {
+        self.value.capabilities.unhandled_prompt_behavior()
+    }
 }
 
-use std::collections::BTreeMap;
+///
+/// The value of a browser preference, serialized as the JSON type it holds.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(untagged)]
+pub enum PrefValue<'c> {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(Cow<'c, str>),
+}
 
-// a BTreeMap wrapper which supports customizable serialization for Map's value.
-#[derive(Default, Debug)]
-pub(crate) struct VarValTypeMap<K, V> {
-    pub(crate) map: BTreeMap<K, V>,
+impl From<bool> for PrefValue<'_> {
+    fn from(v: bool) -> Self {
+        PrefValue::Bool(v)
+    }
+}
+
+impl From<i32> for PrefValue<'_> {
+    fn from(v: i32) -> Self {
+        PrefValue::Int(v as i64)
+    }
+}
+
+impl From<u32> for PrefValue<'_> {
+    fn from(v: u32) -> Self {
+        PrefValue::Int(v as i64)
+    }
+}
+
+impl From<i64> for PrefValue<'_> {
+    fn from(v: i64) -> Self {
+        PrefValue::Int(v)
+    }
+}
+
+impl From<f64> for PrefValue<'_> {
+    fn from(v: f64) -> Self {
+        PrefValue::Float(v)
+    }
+}
+
+impl<'c> From<&'c str> for PrefValue<'c> {
+    fn from(v: &'c str) -> Self {
+        PrefValue::String(Cow::from(v))
+    }
+}
+
+impl From<String> for PrefValue<'_> {
+    fn from(v: String) -> Self {
+        PrefValue::String(Cow::from(v))
+    }
 }
 
 mod ser {
     use super::*;
-    use serde::ser::{Serialize, SerializeMap, SerializeStruct, Serializer};
-    use std::borrow::Cow;
+    use serde::ser::{Serialize, SerializeStruct, Serializer};
 
     impl Serialize for Proxy<'_> {
         fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -573,26 +670,6 @@ mod ser {
             state.end()
         }
     }
-
-    impl Serialize for VarValTypeMap<Cow<'_, str>, Cow<'_, str>> {
-        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-            let mut state = serializer.serialize_map(Some(self.map.len()))?;
-
-            for (key, value) in self.map.iter() {
-                if value == "true" {
-                    state.serialize_entry(key, &true)?;
-                } else if value == "false" {
-                    state.serialize_entry(key, &false)?;
-                } else if value.parse::<u32>().is_ok() {
-                    state.serialize_entry(key, &value.parse::<u32>().unwrap())?;
-                } else {
-                    state.serialize_entry(key, value)?;
-                }
-            }
-
-            state.end()
-        }
-    }
 } // ser
 
 mod deser_proxyref {
@@ -845,7 +922,7 @@ mod deser_timeouts {
         where
             A: MapAccess<'de>,
         {
-            let mut script: Option<u32> = None;
+            let mut script: Option<Option<u32>> = None; // null is no timeout
             let mut page_load: Option<u32> = None;
             let mut implicit: Option<u32> = None;
 
@@ -10,6 +10,7 @@
 //
 
 pub mod any {
+    use super::super::comm::PrefValue;
     use super::super::w3c;
     use super::any as gecko;
 
@@ -37,7 +38,7 @@ pub mod any {
         fn profile(&self) -> Option<&str> {
             None
         }
-        fn prefs(&self) -> Option<BTreeMap<&str, &str>> {
+        fn prefs(&self) -> Option<BTreeMap<&str, &PrefValue<'_>>> {
             None
         }
         fn android_package(&self) -> Option<&str> {
@@ -55,8 +56,8 @@ pub mod any {
         fn add_args(&mut self, _arg: &'c str) {}
         fn set_profile(&mut self, _arg: &'c str) {}
         fn set_profile_take(&mut self, _arg: String) {}
-        fn set_prefs(&mut self, _arg: BTreeMap<&'c str, &'c str>) {}
-        fn add_prefs(&mut self, _key: &'c str, _value: &'c str) {}
+        fn set_prefs(&mut self, _arg: BTreeMap<&'c str, PrefValue<'c>>) {}
+        fn add_prefs(&mut self, _key: &'c str, _value: impl Into<PrefValue<'c>>) {}
         fn set_android_package(&mut self, _arg: &'c str) {}
     }
 } // any
@@ -67,7 +68,7 @@ use super::comm;
 use super::w3c;
 use any as gecko;
 
-use comm::VarValTypeMap;
+use comm::PrefValue;
 use gecko::{FirefoxCapaGetter, FirefoxCapaSetter};
 use w3c::{W3cCapaGetter, W3cCapaSetter};
 
@@ -80,7 +81,7 @@ pub struct FirefoxSesExtCap<'c> {
     binary: Option<Cow<'c, str>>,
     args: Option<Vec<Cow<'c, str>>>,
     profile: Option<Cow<'c, str>>,
-    prefs: Option<VarValTypeMap<Cow<'c, str>, Cow<'c, str>>>,
+    prefs: Option<BTreeMap<Cow<'c, str>, PrefValue<'c>>>,
     // android_package: Option<String>,
 }
 
@@ -244,7 +245,7 @@ where
             newme.set_profile(v);
         }
         if let Some(v) = other.prefs() {
-            newme.set_prefs(v);
+            newme.set_prefs(v.into_iter().map(|(k, v)| (k, v.clone())).collect());
         }
 
         newme
@@ -306,17 +307,12 @@ impl gecko::FirefoxCapaGetter for FirefoxCapa<'_> {
             Some(v) => Some(v),
         }
     }
-    fn prefs(&self) -> Option<BTreeMap<&str, &str>> {
+    fn prefs(&self) -> Option<BTreeMap<&str, &PrefValue<'_>>> {
         let prefs = self.ext.prefs.as_ref();
 
         match prefs {
             None => None,
-            Some(v) => Some(
-                v.map
-                    .iter()
-                    .map(|(k, v)| (k.as_ref(), v.as_ref()))
-                    .collect(),
-            ),
+            Some(v) => Some(v.iter().map(|(k, v)| (k.as_ref(), v)).collect()),
         }
     }
     fn is_insig(&self) -> bool {
@@ -351,24 +347,18 @@ where
     fn set_profile_take(&mut self, arg: String) {
         self.ext.profile = Some(Cow::from(arg));
     }
-    fn set_prefs(&mut self, arg: BTreeMap<&'c str, &'c str>) {
-        let mut newone = VarValTypeMap::<Cow<'c, str>, Cow<'c, str>>::default();
-        for (key, value) in arg.iter() {
-            newone.map.insert(Cow::from(*key), Cow::from(*value));
-        }
-        self.ext.prefs = Some(newone);
+    fn set_prefs(&mut self, arg: BTreeMap<&'c str, PrefValue<'c>>) {
+        self.ext.prefs = Some(
+            arg.into_iter()
+                .map(|(key, value)| (Cow::from(key), value))
+                .collect(),
+        );
     }
-    fn add_prefs(&mut self, key: &'c str, value: &'c str) {
-        match self.ext.prefs.as_mut() {
-            Some(v) => {
-                v.map.insert(Cow::from(key), Cow::from(value));
-            }
-            None => {
-                let mut newone = BTreeMap::<&'c str, &'c str>::default();
-                newone.insert(key, value);
-                self.set_prefs(newone);
-            }
-        }
+    fn add_prefs(&mut self, key: &'c str, value: impl Into<PrefValue<'c>>) {
+        self.ext
+            .prefs
+            .get_or_insert_with(BTreeMap::new)
+            .insert(Cow::from(key), value.into());
     }
 }
 
@@ -508,6 +498,12 @@ mod ser {
             if let Some(v) = self.strict_file_interactability() {
                 state.serialize_field("strictFileInteractability", &v)?;
             }
+            if let Some(v) = self.window_rect() {
+                state.serialize_field("setWindowRect", &v)?;
+            }
+            if let Some(v) = self.unhandled_prompt_behavior() {
+                state.serialize_field("unhandledPromptBehavior", &v)?;
+            }
             if let Some(v) = &self.proxy {
                 state.serialize_field("proxy", v)?;
             }
@@ -820,9 +816,7 @@ mod deser {
                     ret.proxy = Some(_f5);
                 }
                 ret.set_window_rect(f6);
-                ret.set_timeouts_script(f7.script);
-                ret.set_timeouts_page_load(f7.page_load);
-                ret.set_timeouts_implicit(f7.implicit);
+                ret.timeouts = Some(f7);
                 ret.set_strict_file_interactability(f8);
                 ret.set_unhandled_prompt_behavior(f9);
 
@@ -945,9 +939,7 @@ mod deser {
                 ret.set_page_load_strategy_take(f4);
                 ret.proxy = Some(f5);
                 ret.set_window_rect(f6);
-                ret.set_timeouts_script(f7.script);
-                ret.set_timeouts_page_load(f7.page_load);
-                ret.set_timeouts_implicit(f7.implicit);
+                ret.timeouts = Some(f7);
                 ret.set_strict_file_interactability(f8);
                 ret.set_unhandled_prompt_behavior_take(f9);
 
@@ -1375,7 +1367,7 @@ mod utst {
 
             capa.set_binary("/path/to/firefox");
             capa.add_prefs("browser.urlbar.placeholderName", "My Search Engine");
-            capa.add_prefs("javascript.enabled", "false");
+            capa.add_prefs("javascript.enabled", false);
 
             requ.mandate(&capa);
 
@@ -1634,6 +1626,7 @@ mod utst {
                         len: 3,
                     },
                     Token::Str("script"),
+                    Token::Some,
                     Token::U32(1234),
                     Token::Str("pageLoad"),
                     Token::U32(300_000),
@@ -1912,7 +1905,7 @@ mod utst {
             capa.set_browser_name("firefox-nightly");
             capa.set_binary("/path/to/firefox");
             capa.add_prefs("browser.urlbar.placeholderName", "My Search Engine");
-            capa.add_prefs("javascript.enabled", "false");
+            capa.add_prefs("javascript.enabled", false);
 
             requ.mandate(&capa);
 
@@ -1961,7 +1954,7 @@ mod utst {
             capa.set_proxy_type("manual");
             capa.set_socks_version(5);
             capa.set_socks_proxy("127.0.0.1:1080");
-            capa.add_prefs("network.proxy.socks_remote_dns", "true");
+            capa.add_prefs("network.proxy.socks_remote_dns", true);
             requ.mandate(&capa);
 
             // token stream is correct?
@@ -2004,6 +1997,54 @@ mod utst {
             let expect = r#"{"alwaysMatch":{"proxy":{"proxyType":"manual","socksProxy":"127.0.0.1:1080","socksVersion":5},"moz:firefoxOptions":{"prefs":{"network.proxy.socks_remote_dns":true}}},"firstMatch":[]}"#;
             assert_eq!(actual, expect);
         }
+
+        #[test]
+        fn _888() {
+            // `prefs` field, keep the exact type of each value
+
+            let mut requ = GeckoCapRequ::default();
+
+            let mut capa = FirefoxCapa::default();
+            capa.add_prefs("a.bool", true);
+            capa.add_prefs("a.int", -1);
+            capa.add_prefs("a.float", 1.5);
+            capa.add_prefs("a.str", "true");
+            capa.add_prefs("a.strnum", "1");
+            capa.add_prefs("a.string", String::from("x"));
+            requ.mandate(&capa);
+
+            let actual = serde_json::to_string(&requ).unwrap();
+            let expect = r#"{"alwaysMatch":{"moz:firefoxOptions":{"prefs":{"a.bool":true,"a.float":1.5,"a.int":-1,"a.str":"true","a.string":"x","a.strnum":"1"}}},"firstMatch":[]}"#;
+            assert_eq!(actual, expect);
+            assert_eq!(
+                requ.always_match.prefs().unwrap()["a.int"],
+                &PrefValue::Int(-1)
+            );
+        }
+
+        #[test]
+        fn _9() {
+            let mut requ = GeckoCapRequ::default();
+
+            let mut capa = FirefoxCapa::default();
+            capa.set_window_rect(true);
+            requ.mandate_as_w3c(&capa);
+
+            // json str is correct?
+            let actual = serde_json::to_string(&requ).unwrap();
+            let expect = r#"{"alwaysMatch":{"setWindowRect":true},"firstMatch":[]}"#;
+            assert_eq!(actual, expect);
+
+            let mut requ = GeckoCapRequ::default();
+
+            let mut capa = FirefoxCapa::default();
+            capa.set_unhandled_prompt_behavior("ignore");
+            requ.mandate_as_w3c(&capa);
+
+            let actual = serde_json::to_string(&requ).unwrap();
+            let expect = r#"{"alwaysMatch":{"unhandledPromptBehavior":"ignore"},"firstMatch":[]}"#;
+            assert_eq!(actual, expect);
+        }
     } // ser
 
     #[cfg(test)]
@@ -28,6 +28,8 @@ mod comm;
 
 pub use w3c::{W3cCapRequSetter, W3cCapaGetter, W3cCapaSetter, W3cSessResultGetter};
 
+pub use comm::{PrefValue, Timeouts};
+
 #[cfg(feature = "firefox")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "firefox")))]
 pub use gec::any::{FirefoxCapaGetter, FirefoxCapaSetter, GeckoCapRequSetter};
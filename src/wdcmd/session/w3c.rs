@@ -176,4 +176,14 @@ pub trait W3cSessResultGetter {
     /// of binding session.
     fn session_id(&self) -> &str;
     fn wsurl(&self) -> Option<&str>;
+    ///
+    /// Whether the session supports resizing and repositioning windows.
+    fn window_rect(&self) -> Option<bool> {
+        None
+    }
+    ///
+    /// How the session handles user prompts that no command deals with.
+    fn unhandled_prompt_behavior(&self) -> Option<&str> {
+        None
+    }
 }
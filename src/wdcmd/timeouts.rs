@@ -0,0 +1,65 @@
+// Copyright (C) 2023 Michael Lee <imichael2e2@proton.me/...@gmail.com>
+//
+// Licensed under the MIT License <LICENSE-MIT or
+// https://opensource.org/license/mit> or the GNU General Public License,
+// Version 3.0 or any later version <LICENSE-GPL or
+// https://www.gnu.org/licenses/gpl-3.0.txt>, at your option.
+//
+// This file may not be copied, modified, or distributed except except in
+// compliance with either of the licenses.
+//
+
+use super::session::Timeouts;
+
+///
+/// Get details of "Get Timeouts" command processing result.
+pub trait GetTimeoutsResultGetter {
+    fn timeouts(&self) -> &Timeouts;
+}
+
+///
+/// The "Get Timeouts" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetTimeoutsResult {
+    value: Timeouts,
+}
+
+impl GetTimeoutsResultGetter for GetTimeoutsResult {
+    fn timeouts(&self) -> &Timeouts {
+        &self.value
+    }
+}
+
+#[cfg(test)]
+mod utst {
+    use super::*;
+
+    #[test]
+    fn _1() {
+        let res: GetTimeoutsResult =
+            serde_json::from_str(r#"{"value":{"implicit":0,"pageLoad":300000,"script":30000}}"#)
+                .unwrap();
+        assert_eq!(res.timeouts(), &Timeouts::default());
+
+        let mut timeouts = *res.timeouts();
+        timeouts.set_page_load(60_000).set_implicit(500);
+        assert_eq!(
+            serde_json::to_string(&timeouts).unwrap(),
+            r#"{"script":30000,"pageLoad":60000,"implicit":500}"#
+        );
+    }
+
+    #[test]
+    fn _2() {
+        let res: GetTimeoutsResult =
+            serde_json::from_str(r#"{"value":{"implicit":0,"pageLoad":300000,"script":null}}"#)
+                .unwrap();
+        assert_eq!(res.timeouts().script(), None);
+        assert_eq!(res.timeouts().page_load(), 300_000);
+
+        assert_eq!(
+            serde_json::to_string(res.timeouts()).unwrap(),
+            r#"{"script":null,"pageLoad":300000,"implicit":0}"#
+        );
+    }
+}
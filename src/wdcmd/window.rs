@@ -0,0 +1,272 @@
+// Copyright (C) 2023 Michael Lee <imichael2e2@proton.me/...@gmail.com>
+//
+// Licensed under the MIT License <LICENSE-MIT or
+// https://opensource.org/license/mit> or the GNU General Public License,
+// Version 3.0 or any later version <LICENSE-GPL or
+// https://www.gnu.org/licenses/gpl-3.0.txt>, at your option.
+//
+// This file may not be copied, modified, or distributed except except in
+// compliance with either of the licenses.
+//
+
+///
+/// The handle of a top-level browsing context, i.e. a window or a tab.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct WindowHandle {
+    id: String,
+}
+
+impl WindowHandle {
+    #[inline]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+///
+/// The type hint of the top-level browsing context to be created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WindowType {
+    Tab,
+    Window,
+}
+
+///
+/// The position and size of a window, in CSS pixels.
+#[derive(Debug, Default, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct WindowRect {
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+}
+
+impl WindowRect {
+    pub fn new(x: i32, y: i32, width: u32, height: u32) -> Self {
+        WindowRect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[inline]
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+
+    #[inline]
+    pub fn y(&self) -> i32 {
+        self.y
+    }
+
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+}
+
+///
+/// The frame to switch to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameId<'a> {
+    ///
+    /// The top-level browsing context.
+    Top,
+    ///
+    /// The frame at index of current browsing context's `window.frames`.
+    Index(u16),
+    ///
+    /// The `frame` or `iframe` element, by element id.
+    Elem(&'a str),
+}
+
+///
+/// Get details of "Get Window Handle" command processing result.
+pub trait GetWindowHandleResultGetter {
+    fn handle(&self) -> &WindowHandle;
+}
+
+///
+/// Get details of "Get Window Handles" and "Close Window" command processing
+/// result.
+pub trait GetWindowHandlesResultGetter {
+    fn handles(&self) -> &[WindowHandle];
+}
+
+///
+/// Get details of "New Window" command processing result.
+pub trait NewWindowResultGetter {
+    fn handle(&self) -> &WindowHandle;
+    fn window_type(&self) -> WindowType;
+}
+
+///
+/// Get details of "Get/Set Window Rect", "Maximize/Minimize Window" and
+/// "Fullscreen Window" command processing result.
+pub trait GetWindowRectResultGetter {
+    fn rect(&self) -> &WindowRect;
+}
+
+///
+/// The "Get Window Handle" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetWindowHandleResult {
+    value: WindowHandle,
+}
+
+///
+/// The "Get Window Handles" and "Close Window" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetWindowHandlesResult {
+    value: Vec<WindowHandle>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct NewWindow {
+    handle: WindowHandle,
+    #[serde(rename = "type")]
+    window_type: WindowType,
+}
+
+///
+/// The "New Window" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct NewWindowResult {
+    value: NewWindow,
+}
+
+///
+/// The "Get/Set Window Rect", "Maximize/Minimize Window" and
+/// "Fullscreen Window" command processing result.
+#[derive(Debug, serde::Deserialize)]
+pub struct GetWindowRectResult {
+    value: WindowRect,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct SwitchToWindowParam<'a> {
+    pub(crate) handle: &'a str,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct NewWindowParam {
+    #[serde(rename = "type")]
+    pub(crate) window_type: WindowType,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct SwitchToFrameParam<'a> {
+    pub(crate) id: FrameId<'a>,
+}
+
+impl GetWindowHandleResultGetter for GetWindowHandleResult {
+    fn handle(&self) -> &WindowHandle {
+        &self.value
+    }
+}
+
+impl GetWindowHandlesResultGetter for GetWindowHandlesResult {
+    fn handles(&self) -> &[WindowHandle] {
+        &self.value
+    }
+}
+
+impl NewWindowResultGetter for NewWindowResult {
+    fn handle(&self) -> &WindowHandle {
+        &self.value.handle
+    }
+    fn window_type(&self) -> WindowType {
+        self.value.window_type
+    }
+}
+
+impl GetWindowRectResultGetter for GetWindowRectResult {
+    fn rect(&self) -> &WindowRect {
+        &self.value
+    }
+}
+
+mod ser {
+    use super::*;
+    use serde::ser::{Serialize, SerializeMap, Serializer};
+
+    impl Serialize for FrameId<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                FrameId::Top => serializer.serialize_none(),
+                FrameId::Index(v) => serializer.serialize_u16(*v),
+                FrameId::Elem(v) => {
+                    let mut state = serializer.serialize_map(Some(1))?;
+                    state.serialize_entry("element-6066-11e4-a52e-4f735466cecf", v)?;
+                    state.end()
+                }
+            }
+        }
+    }
+} // ser
+
+#[cfg(test)]
+mod utst {
+    use super::*;
+
+    #[test]
+    fn _1() {
+        let res: NewWindowResult =
+            serde_json::from_str(r#"{"value":{"handle":"15","type":"tab"}}"#).unwrap();
+        assert_eq!(res.handle().id(), "15");
+        assert_eq!(res.window_type(), WindowType::Tab);
+
+        let res: GetWindowHandlesResult = serde_json::from_str(r#"{"value":["15","17"]}"#).unwrap();
+        assert_eq!(res.handles().len(), 2);
+        assert_eq!(res.handles()[1].id(), "17");
+    }
+
+    #[test]
+    fn _2() {
+        let res: GetWindowRectResult =
+            serde_json::from_str(r#"{"value":{"height":600,"width":800,"x":-4,"y":22}}"#).unwrap();
+        assert_eq!(res.rect(), &WindowRect::new(-4, 22, 800, 600));
+
+        assert_eq!(
+            serde_json::to_string(&WindowRect::new(0, 0, 1024, 768)).unwrap(),
+            r#"{"x":0,"y":0,"width":1024,"height":768}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&NewWindowParam {
+                window_type: WindowType::Window
+            })
+            .unwrap(),
+            r#"{"type":"window"}"#
+        );
+    }
+
+    #[test]
+    fn _3() {
+        let actual = serde_json::to_string(&SwitchToFrameParam { id: FrameId::Top }).unwrap();
+        assert_eq!(actual, r#"{"id":null}"#);
+
+        let actual = serde_json::to_string(&SwitchToFrameParam {
+            id: FrameId::Index(2),
+        })
+        .unwrap();
+        assert_eq!(actual, r#"{"id":2}"#);
+
+        let actual = serde_json::to_string(&SwitchToFrameParam {
+            id: FrameId::Elem("a1b2"),
+        })
+        .unwrap();
+        assert_eq!(
+            actual,
+            r#"{"id":{"element-6066-11e4-a52e-4f735466cecf":"a1b2"}}"#
+        );
+    }
+}
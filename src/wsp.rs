@@ -9,6 +9,7 @@
 // compliance with either of the licenses.
 //
 
+use crate::httpp::HttpError;
 use crate::httpp::HttpRequestParts;
 use crate::httpp::HttpResponseParts;
 
@@ -36,6 +37,47 @@ pub enum WspError {
     SizeKindNotFound,
     HandshakeFail1,
     HandshakeFail2,
+    Io(std::io::ErrorKind, String),
+}
+
+impl From<std::io::Error> for WspError {
+    fn from(e: std::io::Error) -> Self {
+        WspError::Io(e.kind(), e.to_string())
+    }
+}
+
+// HTTP is spoken only during handshake
+impl From<HttpError> for WspError {
+    fn from(e: HttpError) -> Self {
+        match e {
+            HttpError::Io(e) => e.into(),
+            _ => WspError::HandshakeFail2,
+        }
+    }
+}
+
+impl std::fmt::Display for WspError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WspError::Buggy => write!(f, "a possible bug found"),
+            WspError::InvalidMaxFrameLen => write!(f, "invalid max frame length"),
+            WspError::InvalidDataLen => write!(f, "invalid data length"),
+            WspError::InsufficientSize => write!(f, "insufficient frame size"),
+            WspError::SizeKindNotFound => write!(f, "no suitable frame size"),
+            WspError::HandshakeFail1 => write!(f, "handshake rejected"),
+            WspError::HandshakeFail2 => write!(f, "handshake response is invalid"),
+            WspError::Io(_, msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl From<WspError> for crate::WdcError {
+    fn from(e: WspError) -> Self {
+        match e {
+            WspError::Io(kind, msg) => crate::WdcError::Io(kind, msg),
+            e => crate::WdcError::BadWebSocket(e.to_string()),
+        }
+    }
 }
 
 pub struct WebSocketHandshaker;
@@ -49,9 +91,9 @@ impl WebSocketHandshaker {
             .host(host)
             .ws_key("aG93LXRvLWhhbmRzaGFrZQ==")
             .ws_ver("13");
-        req.send_through(stream).unwrap();
+        req.send_through(stream)?;
 
-        let resp = HttpResponseParts::from_stream(stream, None, 0, 0).unwrap();
+        let resp = HttpResponseParts::from_stream(stream, None, 0, 0)?;
 
         match resp.get_status() {
             Ok(status_reason) => {
@@ -275,7 +317,7 @@ impl WebSocketMessage {
         while !is_all_frames_read {
             let mut newframe = WebSocketFrame::new();
 
-            stream.read_exact(&mut rbuf[0..2]).unwrap();
+            stream.read_exact(&mut rbuf[0..2])?;
 
             let opcode = rbuf[0];
             let plen_s = rbuf[1];
@@ -290,11 +332,11 @@ impl WebSocketMessage {
                 newframe.size_kind = SizeKind::S; // !
                 plen_hint as usize
             } else if plen_hint == 126 {
-                stream.read_exact(&mut rbuf[0..2]).unwrap();
+                stream.read_exact(&mut rbuf[0..2])?;
                 newframe.size_kind = SizeKind::M; // !
                 u16::from_be_bytes([rbuf[0], rbuf[1]]) as usize
             } else if plen_hint == 127 {
-                stream.read_exact(&mut rbuf[0..8]).unwrap();
+                stream.read_exact(&mut rbuf[0..8])?;
                 newframe.size_kind = SizeKind::L; // !
                 u64::from_be_bytes([
                     rbuf[0], rbuf[1], rbuf[2], rbuf[3], rbuf[4], rbuf[5], rbuf[6], rbuf[7],
@@ -308,7 +350,7 @@ impl WebSocketMessage {
 
             let mut mask_key = 0u32;
             if is_mask {
-                stream.read_exact(&mut rbuf[0..4]).unwrap();
+                stream.read_exact(&mut rbuf[0..4])?;
                 mask_key = u32::from_be_bytes([rbuf[0], rbuf[1], rbuf[2], rbuf[3]]);
                 newframe.mkey = Some(mask_key); // !
             }
@@ -316,7 +358,7 @@ impl WebSocketMessage {
             if plen > rbuf.len() {
                 rbuf.resize(plen, 0);
             }
-            stream.read_exact(&mut rbuf[0..plen]).unwrap();
+            stream.read_exact(&mut rbuf[0..plen])?;
 
             let mut pl_data = Vec::<u8>::new();
             pl_data.extend(&rbuf[0..plen]);
@@ -345,7 +387,7 @@ impl WebSocketMessage {
 
     pub fn send_through(&self, stream: &mut TcpStream) -> Result<(), WspError> {
         let bytes = self.to_vec();
-        stream.write_all(&bytes[..]).unwrap();
+        stream.write_all(&bytes[..])?;
         Ok(())
     }
 }
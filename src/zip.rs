@@ -0,0 +1,220 @@
+// Copyright (C) 2023 Michael Lee <imichael2e2@proton.me/...@gmail.com>
+//
+// Licensed under the MIT License <LICENSE-MIT or
+// https://opensource.org/license/mit> or the GNU General Public License,
+// Version 3.0 or any later version <LICENSE-GPL or
+// https://www.gnu.org/licenses/gpl-3.0.txt>, at your option.
+//
+// This file may not be copied, modified, or distributed except except in
+// compliance with either of the licenses.
+//
+
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+
+///
+/// Pack all files under `dir` into a ZIP archive, entries are stored without
+/// compression, and named by their paths relative to `dir`.
+///
+/// Symbolic links are followed, a link to a directory is packed as that
+/// directory, and one pointing back to a directory being packed fails with
+/// [`ErrorKind::InvalidInput`].
+///
+/// This is just enough for packing a Firefox add-on, no other ZIP features
+/// are supported.
+pub(crate) fn zip_dir(dir: &Path) -> Result<Vec<u8>, Error> {
+    let mut files = vec![];
+    collect_files(dir, "", &mut files, &mut vec![])?;
+    files.sort();
+
+    let mut entries = vec![];
+    for name in files {
+        let data = fs::read(dir.join(&name))?;
+        entries.push((name, data));
+    }
+
+    Ok(zip_entries(&entries))
+}
+
+// `ancestors` are the real paths of directories being walked, to catch loops
+fn collect_files(
+    dir: &Path,
+    prefix: &str,
+    files: &mut Vec<String>,
+    ancestors: &mut Vec<PathBuf>,
+) -> Result<(), Error> {
+    let real_dir = fs::canonicalize(dir)?;
+    if ancestors.contains(&real_dir) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("{} links back to a parent directory", dir.display()),
+        ));
+    }
+    ancestors.push(real_dir);
+
+    for ent in fs::read_dir(dir)? {
+        let ent = ent?;
+        let name = format!("{}{}", prefix, ent.file_name().to_string_lossy());
+        // metadata of the link target, if it is a symbolic link
+        if fs::metadata(ent.path())?.is_dir() {
+            collect_files(&ent.path(), &format!("{}/", name), files, ancestors)?;
+        } else {
+            files.push(name);
+        }
+    }
+
+    ancestors.pop();
+
+    Ok(())
+}
+
+// 1980-01-01 00:00:00, the earliest time ZIP can represent
+const DOS_TIME: u16 = 0;
+const DOS_DATE: u16 = (1 << 5) | 1;
+
+fn zip_entries(entries: &[(String, Vec<u8>)]) -> Vec<u8> {
+    let mut out = Vec::<u8>::new();
+    let mut cdir = Vec::<u8>::new();
+
+    for (name, data) in entries {
+        let offset = out.len() as u32;
+        let crc = crc32(data);
+        let size = data.len() as u32;
+
+        // local file header
+        out.extend(0x04034b50u32.to_le_bytes());
+        out.extend(20u16.to_le_bytes()); // version needed
+        out.extend(0u16.to_le_bytes()); // flags
+        out.extend(0u16.to_le_bytes()); // stored
+        out.extend(DOS_TIME.to_le_bytes());
+        out.extend(DOS_DATE.to_le_bytes());
+        out.extend(crc.to_le_bytes());
+        out.extend(size.to_le_bytes()); // compressed
+        out.extend(size.to_le_bytes()); // uncompressed
+        out.extend((name.len() as u16).to_le_bytes());
+        out.extend(0u16.to_le_bytes()); // extra field length
+        out.extend(name.as_bytes());
+        out.extend(data);
+
+        // central directory header
+        cdir.extend(0x02014b50u32.to_le_bytes());
+        cdir.extend(20u16.to_le_bytes()); // version made by
+        cdir.extend(20u16.to_le_bytes()); // version needed
+        cdir.extend(0u16.to_le_bytes()); // flags
+        cdir.extend(0u16.to_le_bytes()); // stored
+        cdir.extend(DOS_TIME.to_le_bytes());
+        cdir.extend(DOS_DATE.to_le_bytes());
+        cdir.extend(crc.to_le_bytes());
+        cdir.extend(size.to_le_bytes());
+        cdir.extend(size.to_le_bytes());
+        cdir.extend((name.len() as u16).to_le_bytes());
+        cdir.extend(0u16.to_le_bytes()); // extra field length
+        cdir.extend(0u16.to_le_bytes()); // comment length
+        cdir.extend(0u16.to_le_bytes()); // disk number
+        cdir.extend(0u16.to_le_bytes()); // internal attributes
+        cdir.extend(0u32.to_le_bytes()); // external attributes
+        cdir.extend(offset.to_le_bytes());
+        cdir.extend(name.as_bytes());
+    }
+
+    let cdir_offset = out.len() as u32;
+    out.extend(&cdir);
+
+    // end of central directory record
+    out.extend(0x06054b50u32.to_le_bytes());
+    out.extend(0u16.to_le_bytes()); // this disk
+    out.extend(0u16.to_le_bytes()); // disk with central directory
+    out.extend((entries.len() as u16).to_le_bytes());
+    out.extend((entries.len() as u16).to_le_bytes());
+    out.extend((cdir.len() as u32).to_le_bytes());
+    out.extend(cdir_offset.to_le_bytes());
+    out.extend(0u16.to_le_bytes()); // comment length
+
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xffffffffu32;
+    for b in data {
+        crc ^= *b as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb88320 & mask);
+        }
+    }
+
+    !crc
+}
+
+#[cfg(test)]
+mod utst {
+    use super::*;
+
+    #[test]
+    fn _1() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+    }
+
+    #[test]
+    fn _2() {
+        let zip = zip_entries(&[("a/b.txt".to_string(), b"hi".to_vec())]);
+
+        // local file header, then data
+        assert_eq!(&zip[..4], b"PK\x03\x04");
+        assert_eq!(&zip[26..28], 7u16.to_le_bytes());
+        assert_eq!(&zip[30..37], b"a/b.txt");
+        assert_eq!(&zip[37..39], b"hi");
+        // central directory header
+        assert_eq!(&zip[39..43], b"PK\x01\x02");
+        // end of central directory record, pointing to the header above
+        let eocd = &zip[zip.len() - 22..];
+        assert_eq!(&eocd[..4], b"PK\x05\x06");
+        assert_eq!(&eocd[10..12], 1u16.to_le_bytes());
+        assert_eq!(&eocd[16..20], 39u32.to_le_bytes());
+    }
+
+    #[test]
+    fn _3() {
+        let dir = std::env::temp_dir().join(format!("wdc-zip-{}", std::process::id()));
+        fs::create_dir_all(dir.join("icons")).unwrap();
+        fs::write(dir.join("manifest.json"), b"{}").unwrap();
+        fs::write(dir.join("icons").join("x.png"), b"png").unwrap();
+
+        let zip = zip_dir(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        // entries are sorted, and named with "/"
+        assert_eq!(&zip[30..41], b"icons/x.png");
+        assert_eq!(&zip[41..44], b"png");
+        assert_eq!(&zip[44..48], b"PK\x03\x04");
+        assert_eq!(&zip[74..87], b"manifest.json");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn _4() {
+        use std::os::unix::fs::symlink;
+
+        let root = std::env::temp_dir().join(format!("wdc-zip-link-{}", std::process::id()));
+        let (dir, shared) = (root.join("addon"), root.join("shared"));
+        fs::create_dir_all(&dir).unwrap();
+        fs::create_dir_all(&shared).unwrap();
+        fs::write(shared.join("y.js"), b"js").unwrap();
+        symlink(&shared, dir.join("lib")).unwrap();
+
+        // a link to a directory is packed as the directory
+        let zip = zip_dir(&dir);
+
+        // a link back to a parent is refused
+        symlink(&dir, shared.join("loop")).unwrap();
+        let looped = zip_dir(&dir);
+        fs::remove_dir_all(&root).unwrap();
+
+        let zip = zip.unwrap();
+        assert_eq!(&zip[30..38], b"lib/y.js");
+        assert_eq!(&zip[38..40], b"js");
+        assert_eq!(looped.unwrap_err().kind(), ErrorKind::InvalidInput);
+    }
+}
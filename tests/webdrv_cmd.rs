@@ -1,10 +1,17 @@
 mod webdrv_cmds {
 
-    use wdc::WdcError::BadDrvCmd;
+    use wdc::WdcError::{BadDrvCmd, InvalidArgument, UnexpectedScriptResult};
+
+    use wdc::wdcmd::err::WebDriverErrorCode;
+
+    use wdc::wdcmd::cookie::Cookie;
+    use wdc::wdcmd::find_elem::{ElemRef, Locator};
+    use wdc::wdcmd::print::{Orientation, PageRange, PrintOptions};
+    use wdc::wdcmd::screenshot::png_size;
+    use wdc::wdcmd::window::{FrameId, WindowRect, WindowType};
 
     use wdc::wdcmd::session::W3cCapaSetter;
 
-    use std::fs::OpenOptions;
     use std::io::Read;
     use std::thread::sleep;
     use std::time::Duration;
@@ -12,6 +19,7 @@ mod webdrv_cmds {
     #[cfg(feature = "firefox")]
     mod gecko {
         use super::*;
+        use wdc::wdcmd::moz::GeckoContext;
         use wdc::wdcmd::session::{FirefoxCapa, FirefoxCapaSetter};
         use wdc::GeckoDriver;
 
@@ -31,13 +39,43 @@ mod webdrv_cmds {
                 assert_eq!(cur_url, b"about:rights");
             }
 
+            #[test]
+            fn navi_history1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("data:text/html,<title>one</title>")
+                    .expect("navi to")
+                    .navi_to("data:text/html,<title>two</title>")
+                    .expect("navi to");
+                assert_eq!(wdc.title().expect("get title"), "two");
+                assert_eq!(wdc.back().expect("back").title().expect("get title"), "one");
+                assert_eq!(
+                    wdc.forward().expect("forward").title().expect("get title"),
+                    "two"
+                );
+                assert_eq!(
+                    wdc.refresh().expect("refresh").title().expect("get title"),
+                    "two"
+                );
+            }
+
+            #[test]
+            fn timeouts1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                let mut timeouts = wdc.get_timeouts().expect("get timeouts");
+                timeouts.set_implicit(1500).set_script(Some(12_000));
+                wdc.set_timeouts(&timeouts).expect("set timeouts");
+                let actual = wdc.get_timeouts().expect("get timeouts");
+                assert_eq!(actual.implicit(), 1500);
+                assert_eq!(actual.script(), Some(12_000));
+                assert_eq!(actual.page_load(), timeouts.page_load());
+            }
+
             #[test]
             fn find_elem1() {
                 let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
                 wdc.navi_to("about:rights").expect("navi to");
-                let eleid = wdc.find_elem_css("#your-rights").expect("find elem");
-                let eleid = String::from_utf8_lossy(&eleid);
-                assert!(is_uuid(&eleid));
+                let elem = wdc.find_elem_css("#your-rights").expect("find elem");
+                assert!(is_uuid(elem.id()));
             }
 
             #[test]
@@ -46,86 +84,342 @@ mod webdrv_cmds {
                 wdc.navi_to("about:rights").expect("navi to");
                 let eleids = wdc.find_elems_css("#your-rights").expect("find elem");
                 assert!(eleids.len() > 0);
-                for id in eleids {
-                    assert!(is_uuid(&id));
+                for elem in eleids {
+                    assert!(is_uuid(elem.id()));
                 }
             }
 
             #[test]
-            fn screenshot1() {
+            fn find_elem3() {
                 let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
                 wdc.navi_to("about:rights").expect("navi to");
-                wdc.screenshot(&pbody_file(PBODY_FILENAME_PREFIX, "sshot"))
-                    .expect("screenshot failed");
-                // check b64 data
-                let mut buf = [0u8; 4096];
-                let mut b64png = OpenOptions::new()
-                    .read(true)
-                    .open(&pbody_file(PBODY_FILENAME_PREFIX, "sshot"))
-                    .expect("pbody file");
-                loop {
-                    let nread = b64png.read(&mut buf).unwrap();
-                    if nread == 0 {
+                let eleid1 = wdc.find_elem_css("#your-rights").expect("find elem");
+                let eleid2 = wdc
+                    .find_elem(Locator::XPath("//*[@id='your-rights']"))
+                    .expect("find elem");
+                assert_eq!(eleid1, eleid2);
+                let eleids = wdc.find_elems(Locator::TagName("div")).expect("find elems");
+                assert!(!eleids.is_empty());
+                assert!(matches!(
+                    wdc.find_elem(Locator::LinkText("no such link")),
+                    Err(BadDrvCmd(err)) if err.code() == &WebDriverErrorCode::NoSuchElement
+                ));
+            }
+
+            #[test]
+            fn find_elem_from_elem1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to(
+                    "data:text/html,<ul id='a'><li>1</li><li>2</li></ul><ul id='b'><li>3</li></ul>",
+                )
+                .expect("navi to");
+                let parent = wdc.find_elem_css("#a").expect("find elem");
+                let elem = parent
+                    .find_elem(Locator::Css("li"))
+                    .expect("find elem from elem");
+                assert_eq!(elem.tag_name().expect("get tag name"), "li");
+                let elems = wdc
+                    .find_elems_from_elem(parent.id(), Locator::Css("li"))
+                    .expect("find elems from elem");
+                assert_eq!(elems.len(), 2);
+                assert_eq!(elems[0], elem);
+                assert_eq!(elems[1].text().expect("get text"), "2");
+            }
+
+            #[test]
+            fn find_elem_from_shadow1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("data:text/html,<div id='host'></div>")
+                    .expect("navi to");
+                wdc.exec_sync::<()>(
+                    "document.getElementById('host').attachShadow({mode:'open'}).innerHTML='<p>1</p><p>2</p>';",
+                    (),
+                )
+                .expect("exec sync");
+                let host = wdc.find_elem_css("#host").expect("find elem");
+                let shadowid = host.shadow_root().expect("get shadow root");
+                let elem = wdc
+                    .find_elem_from_shadow(&shadowid, Locator::Css("p"))
+                    .expect("find elem from shadow");
+                assert_eq!(elem.text().expect("get text"), "1");
+                let eleids = wdc
+                    .find_elems_from_shadow(&shadowid, Locator::Css("p"))
+                    .expect("find elems from shadow");
+                assert_eq!(eleids.len(), 2);
+                assert!(wdc.find_elem_css("p").is_err());
+            }
+
+            #[test]
+            fn elem_state1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("about:rights").expect("navi to");
+                let elem = wdc.find_elem_css("#your-rights").expect("find elem");
+                assert_eq!(
+                    elem.attr("id").expect("get attr"),
+                    Some("your-rights".to_string())
+                );
+                assert_eq!(elem.attr("nonexist").expect("get attr"), None);
+                assert!(!elem.text().expect("get text").is_empty());
+                assert!(!elem.tag_name().expect("get tag name").is_empty());
+                assert!(elem.rect().expect("get rect").width() > 0.0);
+                assert!(elem.is_displayed().expect("is displayed"));
+                assert!(elem.is_enabled().expect("is enabled"));
+            }
+
+            #[test]
+            fn elem_interact1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to(
+                    "data:text/html,<input id='t' value='abc'><input id='c' type='checkbox'>",
+                )
+                .expect("navi to");
+                let elem = wdc.find_elem_css("#t").expect("find elem");
+                assert_eq!(elem.prop("value").expect("get prop"), "abc");
+                elem.clear().expect("clear");
+                assert_eq!(elem.prop("value").expect("get prop"), "");
+                assert_eq!(elem.tag_name().expect("get tag name"), "input");
+                assert_eq!(elem.css_value("display").expect("get css"), "inline-block");
+
+                let elem = wdc.find_elem_css("#c").expect("find elem");
+                assert!(!elem.is_selected().expect("is selected"));
+                elem.click().expect("click");
+                assert!(elem.is_selected().expect("is selected"));
+            }
+
+            #[test]
+            fn window1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                let orig = wdc.window_handle().expect("get window handle");
+                let newone = wdc.new_window(WindowType::Tab).expect("new window");
+                let handles = wdc.window_handles().expect("get window handles");
+                assert_eq!(handles.len(), 2);
+                assert!(handles.contains(&orig));
+                assert!(handles.contains(&newone));
+                wdc.switch_to_window(&newone).expect("switch to window");
+                assert_eq!(wdc.window_handle().expect("get window handle"), newone);
+                let remains = wdc.close_window().expect("close window");
+                assert_eq!(remains, vec![orig.clone()]);
+                wdc.switch_to_window(&orig).expect("switch to window");
+            }
+
+            #[test]
+            fn window_rect1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                let ssmeta = wdc.last_session_meta().expect("session meta");
+                assert_eq!(ssmeta.window_rect(), Some(true));
+                let rect = wdc
+                    .set_window_rect(&WindowRect::new(10, 20, 800, 600))
+                    .expect("set window rect");
+                assert_eq!(rect.width(), 800);
+                assert_eq!(rect.height(), 600);
+                assert_eq!(wdc.window_rect().expect("get window rect"), rect);
+                let rect = wdc.maximize_window().expect("maximize window");
+                assert!(rect.width() >= 800);
+            }
+
+            #[test]
+            fn frame1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("data:text/html,<iframe srcdoc='<p id=x>in</p>'></iframe>")
+                    .expect("navi to");
+                assert!(wdc.find_elem_css("#x").is_err());
+                let frame = wdc.find_elem_css("iframe").expect("find elem");
+                let text = wdc
+                    .within_frame(FrameId::Elem(frame.id()), |wdc| {
+                        wdc.find_elem_css("#x")?.text()
+                    })
+                    .expect("within frame");
+                assert_eq!(text, "in");
+                assert!(wdc.find_elem_css("#x").is_err());
+                wdc.switch_to_frame(FrameId::Index(0))
+                    .expect("switch to frame");
+                assert!(wdc.find_elem_css("#x").is_ok());
+                wdc.switch_to_frame(FrameId::Top).expect("switch to frame");
+                assert!(wdc.find_elem_css("#x").is_err());
+            }
+
+            #[test]
+            fn frame2() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to(
+                    r#"data:text/html,<iframe srcdoc="<p id=x>out</p><iframe srcdoc='<p id=y>in</p>'></iframe>"></iframe>"#,
+                )
+                .expect("navi to");
+                let outer = wdc.find_elem_css("iframe").expect("find elem");
+                let text = wdc
+                    .within_frame(FrameId::Elem(outer.id()), |wdc| {
+                        let inner = wdc.find_elem_css("iframe")?;
+                        let text = wdc.within_frame(FrameId::Elem(inner.id()), |wdc| {
+                            wdc.find_elem_css("#y")?.text()
+                        })?;
+                        assert_eq!(wdc.find_elem_css("#x")?.text()?, "out");
+
+                        let ret = wdc.within_frame(FrameId::Top, |_| Ok(()));
+                        assert!(matches!(ret, Err(InvalidArgument(_))));
+                        assert!(wdc.find_elem_css("#x").is_ok());
+
+                        Ok(text)
+                    })
+                    .expect("within frame");
+                assert_eq!(text, "in");
+                assert!(wdc.find_elem_css("#x").is_err());
+            }
+
+            #[test]
+            fn cookie1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to(&local_page("<p>cookie</p>")).expect("navi to");
+                wdc.del_all_cookies().expect("del all cookies");
+                assert!(wdc.all_cookies().expect("get all cookies").is_empty());
+
+                let mut cookie = Cookie::new("foo", "bar");
+                cookie.set_path("/").set_http_only(true);
+                wdc.add_cookie(&cookie).expect("add cookie");
+                wdc.add_cookie(&Cookie::new("baz", "qux"))
+                    .expect("add cookie");
+                assert_eq!(wdc.all_cookies().expect("get all cookies").len(), 2);
+
+                let got = wdc.named_cookie("foo").expect("get named cookie");
+                assert_eq!(got.value(), "bar");
+                assert_eq!(got.path(), Some("/"));
+                assert_eq!(got.http_only(), Some(true));
+
+                wdc.del_cookie("foo").expect("del cookie");
+                assert!(matches!(
+                    wdc.named_cookie("foo"),
+                    Err(BadDrvCmd(err)) if err.code() == &WebDriverErrorCode::NoSuchCookie
+                ));
+                wdc.del_all_cookies().expect("del all cookies");
+                assert!(wdc.all_cookies().expect("get all cookies").is_empty());
+            }
+
+            #[test]
+            fn alert1() {
+                let mut capa = FirefoxCapa::default();
+                capa.set_unhandled_prompt_behavior("ignore");
+                let wdc =
+                    wdc::init_singl::<RendKind>(REND_HOST, REND_PORT, &capa, 10).expect("init wdc");
+                let ssmeta = wdc.last_session_meta().expect("session meta");
+                assert_eq!(ssmeta.unhandled_prompt_behavior(), Some("ignore"));
+
+                wdc.navi_to("data:text/html,<p>alert</p>").expect("navi to");
+                wdc.exec_sync::<()>(
+                    "window.setTimeout(function() { window.result = window.prompt('hi'); }, 0);",
+                    (),
+                )
+                .expect("exec sync");
+                // prompt is opened asynchronously
+                let mut text = wdc.alert_text();
+                for _ in 0..50 {
+                    if text.is_ok() {
                         break;
                     }
-                    for ele in &buf[0..nread] {
-                        if *ele == b'}' || *ele == b'}' || *ele == b'"' {
-                            assert!(false, "invalid base64 data");
-                        }
+                    sleep(Duration::from_millis(100));
+                    text = wdc.alert_text();
+                }
+                assert_eq!(text.expect("get alert text"), Some("hi".to_string()));
+                wdc.send_alert_text("there").expect("send alert text");
+                wdc.accept_alert().expect("accept alert");
+                let result: String = wdc
+                    .exec_sync("return window.result;", ())
+                    .expect("exec sync");
+                assert_eq!(result, "there");
+
+                wdc.exec_sync::<()>(
+                    "window.setTimeout(function() { window.alert('bye'); }, 0);",
+                    (),
+                )
+                .expect("exec sync");
+                let mut text = wdc.alert_text();
+                for _ in 0..50 {
+                    if text.is_ok() {
+                        break;
                     }
+                    sleep(Duration::from_millis(100));
+                    text = wdc.alert_text();
                 }
+                assert_eq!(text.expect("get alert text"), Some("bye".to_string()));
+                wdc.dismiss_alert().expect("dismiss alert");
+                assert!(matches!(
+                    wdc.alert_text(),
+                    Err(BadDrvCmd(err)) if err.code() == &WebDriverErrorCode::NoSuchAlert
+                ));
+            }
+
+            #[test]
+            fn screenshot1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("about:rights").expect("navi to");
+                wdc.screenshot(&pbody_file(PBODY_FILENAME_PREFIX, "sshot"))
+                    .expect("screenshot failed");
+                let data =
+                    std::fs::read(pbody_file(PBODY_FILENAME_PREFIX, "sshot")).expect("pbody file");
+                assert!(png_size(&data).is_some());
             }
 
             #[test]
             fn screenshot_elem1() {
                 let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
                 wdc.navi_to("about:rights").expect("navi to");
-                let eleid = wdc.find_elem_css("#your-rights").expect("find elem");
-                let eleid = String::from_utf8_lossy(&eleid);
-                wdc.screenshot_elem(&eleid, &pbody_file(PBODY_FILENAME_PREFIX, "sshot-elem"))
+                let elem = wdc.find_elem_css("#your-rights").expect("find elem");
+                elem.screenshot(&pbody_file(PBODY_FILENAME_PREFIX, "sshot-elem"))
                     .expect("screenshot failed");
-                // check b64 data
-                let mut buf = [0u8; 4096];
-                let mut b64png = OpenOptions::new()
-                    .read(true)
-                    .open(&pbody_file(PBODY_FILENAME_PREFIX, "sshot-elem"))
+                let data = std::fs::read(pbody_file(PBODY_FILENAME_PREFIX, "sshot-elem"))
                     .expect("pbody file");
-                loop {
-                    let nread = b64png.read(&mut buf).unwrap();
-                    if nread == 0 {
-                        break;
-                    }
-                    for ele in &buf[0..nread] {
-                        if *ele == b'}' || *ele == b'}' || *ele == b'"' {
-                            assert!(false, "invalid base64 data");
-                        }
-                    }
-                }
+                assert!(png_size(&data).is_some());
+            }
+
+            #[test]
+            fn screenshot2() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("data:text/html,<div id='box' style='width:40px;height:30px'></div>")
+                    .expect("navi to");
+                let png = wdc.screenshot_to_vec().expect("screenshot failed");
+                let (width, height) = png_size(&png).expect("png size");
+                assert!(width > 0 && height > 0);
+
+                let elem = wdc.find_elem(Locator::Css("#box")).expect("find elem");
+                let png = elem.screenshot_to_vec().expect("screenshot failed");
+                let (ewidth, eheight) = png_size(&png).expect("png size");
+                assert!((40..=width).contains(&ewidth));
+                assert!((30..=height).contains(&eheight));
+
+                let pdf = wdc
+                    .print_page_to_vec(&PrintOptions::default())
+                    .expect("print page");
+                assert!(pdf.starts_with(b"%PDF-"));
+            }
+
+            #[test]
+            fn print_page2() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("about:blank").expect("navi to");
+                let mut opts = PrintOptions::default();
+                opts.set_orientation(Orientation::Landscape)
+                    .set_page_size(21.0, 29.7)
+                    .set_margin(0.5, 0.5, 1.0, 1.0)
+                    .set_scale(0.8)
+                    .set_shrink_to_fit(false)
+                    .add_page_range(PageRange::Page(1));
+                wdc.print_page(&opts, &pbody_file(PBODY_FILENAME_PREFIX, "ppdf2"))
+                    .expect("print page");
+                let data =
+                    std::fs::read(pbody_file(PBODY_FILENAME_PREFIX, "ppdf2")).expect("pbody file");
+                assert!(data.starts_with(b"%PDF-"));
             }
 
             #[test]
             fn print_page1() {
                 let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
                 wdc.navi_to("about:rights").expect("navi to");
-                wdc.print_page(&pbody_file(PBODY_FILENAME_PREFIX, "ppdf"))
-                    .expect("print page");
-                // check b64 data
-                let mut buf = [0u8; 4096];
-                let mut b64png = OpenOptions::new()
-                    .read(true)
-                    .open(&pbody_file(PBODY_FILENAME_PREFIX, "ppdf"))
-                    .expect("pbody file");
-                loop {
-                    let nread = b64png.read(&mut buf).unwrap();
-                    if nread == 0 {
-                        break;
-                    }
-                    for ele in &buf[0..nread] {
-                        if *ele == b'}' || *ele == b'}' || *ele == b'"' {
-                            assert!(false, "invalid base64 data");
-                        }
-                    }
-                }
+                wdc.print_page(
+                    &PrintOptions::default(),
+                    &pbody_file(PBODY_FILENAME_PREFIX, "ppdf"),
+                )
+                .expect("print page");
+                let data =
+                    std::fs::read(pbody_file(PBODY_FILENAME_PREFIX, "ppdf")).expect("pbody file");
+                assert!(data.starts_with(b"%PDF-"));
             }
 
             #[test]
@@ -164,29 +458,78 @@ mod webdrv_cmds {
                 assert!(retstr.contains("</html>"));
             }
 
+            #[test]
+            fn page_src3() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+
+                wdc.navi_to("data:text/html,<p>hi</p>").expect("navi to");
+
+                let mut sink = std::io::BufWriter::new(Vec::<u8>::new());
+                wdc.page_src_to_writer(&mut sink).expect("page src");
+                let got = sink.into_inner().expect("flush");
+                assert!(String::from_utf8_lossy(&got).contains("<p>hi</p>"));
+
+                let mut sink = std::io::Cursor::new(Vec::<u8>::new());
+                wdc.screenshot_to_writer(&mut sink).expect("screenshot");
+                assert!(png_size(sink.get_ref()).is_some());
+
+                let mut sink = Vec::<u8>::new();
+                wdc.print_page_to_writer(&PrintOptions::default(), &mut sink)
+                    .expect("print page");
+                assert!(sink.starts_with(b"%PDF-"));
+            }
+
+            #[test]
+            fn page_src4() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+
+                let text = "\"quoted\" 'single' a\\b\ncaf\u{e9} \u{65e5}\u{672c} \u{1f600}";
+                wdc.navi_to("data:text/html,<p>hi</p>").expect("navi to");
+                wdc.exec_sync::<()>(
+                    "document.querySelector('p').textContent = arguments[0];",
+                    [text],
+                )
+                .expect("exec sync");
+                let expected = format!("<p>{}</p>", text);
+
+                let got = wdc.page_src(None).expect("page src").expect("in memory");
+                assert!(got
+                    .windows(expected.len())
+                    .any(|w| w == expected.as_bytes()));
+
+                let mut got = Vec::<u8>::new();
+                wdc.page_src_to_writer(&mut got).expect("page src");
+                assert!(got
+                    .windows(expected.len())
+                    .any(|w| w == expected.as_bytes()));
+            }
+
             #[test]
             fn exec_sync1() {
                 let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
 
-                let eval_ret = wdc.exec_sync("return 0xcafe", vec![]).expect("exec_sync");
-                assert_eq!(eval_ret, b"51966");
+                let eval_ret: u32 = wdc.exec_sync("return 0xcafe", ()).expect("exec_sync");
+                assert_eq!(eval_ret, 51966);
+
+                let eval_ret: String = wdc.exec_sync("return '0xcafe'", ()).expect("exec_sync");
+                assert_eq!(eval_ret, "0xcafe");
 
-                let eval_ret = wdc.exec_sync("return '0xcafe'", vec![]).expect("exec_sync");
-                assert_eq!(eval_ret, br#""0xcafe""#);
+                // a mismatched type is not a driver error
+                let eval_ret = wdc.exec_sync::<u32>("return '0xcafe'", ());
+                assert!(matches!(eval_ret, Err(UnexpectedScriptResult(_))));
             }
 
             #[test]
             fn exec_sync2() {
                 let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
 
-                let eval_ret = wdc.exec_sync("throw 123456789", vec![]);
+                let eval_ret = wdc.exec_sync::<()>("throw 123456789", ());
                 assert_eq!(eval_ret.is_ok(), false);
 
-                if let Err(msg) = eval_ret {
-                    assert_eq!(
-                        msg,
-                        BadDrvCmd("javascript error".to_string(), "123456789".to_string())
-                    );
+                if let Err(BadDrvCmd(e)) = eval_ret {
+                    assert_eq!(e.code(), &WebDriverErrorCode::JavascriptError);
+                    assert_eq!(e.message(), "123456789");
+                    assert_eq!(e.http_status(), 500);
                 } else {
                     assert!(false);
                 }
@@ -196,18 +539,57 @@ mod webdrv_cmds {
             fn exec_sync3() {
                 let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
 
-                let eval_ret = wdc
-                    .exec_sync("return arguments[0] + arguments[1];", vec!["3", "4"])
+                let eval_ret: i32 = wdc
+                    .exec_sync("return arguments[0] + arguments[1];", (3, 4))
                     .expect("exec_sync");
-                assert_eq!(eval_ret, b"7");
+                assert_eq!(eval_ret, 7);
 
-                let eval_ret = wdc
-                    .exec_sync(
-                        "return arguments[0] + arguments[1];",
-                        vec!["\"3\"", "\"4\""],
-                    )
+                let eval_ret: String = wdc
+                    .exec_sync("return arguments[0] + arguments[1];", ["3", "4"])
+                    .expect("exec_sync");
+                assert_eq!(eval_ret, "34");
+
+                let eval_ret: Vec<String> = wdc
+                    .exec_sync("return arguments[0].split(',');", vec!["a,b"])
+                    .expect("exec_sync");
+                assert_eq!(eval_ret, vec!["a", "b"]);
+            }
+
+            #[test]
+            fn exec_sync4() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+
+                // quotes, backslashes and newlines are sent as they are
+                let eval_ret: String = wdc
+                    .exec_sync("return \"a\\\\b\" +\n'\"c\"' + arguments[0];", ["\"d\""])
                     .expect("exec_sync");
-                assert_eq!(eval_ret, b"\"34\"");
+                assert_eq!(eval_ret, r#"a\b"c""d""#);
+
+                // arguments must be a sequence
+                let eval_ret = wdc.exec_sync::<()>("return 1;", "x");
+                assert!(matches!(eval_ret, Err(InvalidArgument(_))));
+            }
+
+            #[test]
+            fn exec_sync5() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("data:text/html,<p id='a'>1</p><p id='b'>2</p>")
+                    .expect("navi to");
+
+                // elements as arguments
+                let elem = wdc.find_elem_css("#b").expect("find elem");
+                let text: String = wdc
+                    .exec_sync("return arguments[0].innerText;", [&elem])
+                    .expect("exec_sync");
+                assert_eq!(text, "2");
+
+                // elements as results
+                let elems: Vec<ElemRef> = wdc
+                    .exec_sync("return document.querySelectorAll('p');", ())
+                    .expect("exec_sync");
+                assert_eq!(elems.len(), 2);
+                assert_eq!(wdc.elem_from_ref(&elems[1]), elem);
+                assert_eq!(wdc.elem_from_ref(&elems[0]).text().expect("text"), "1");
             }
 
             #[test]
@@ -218,12 +600,12 @@ mod webdrv_cmds {
                 let wdc =
                     wdc::init_singl::<RendKind>(REND_HOST, REND_PORT, &capa, 10).expect("init wdc");
 
-                let eval_ret = wdc.exec_async("console.log(123)", vec![]);
+                let eval_ret = wdc.exec_async::<()>("console.log(123)", ());
 
                 match eval_ret {
                     Ok(_) => assert!(false),
                     Err(e) => match e {
-                        BadDrvCmd(_, _) => {
+                        BadDrvCmd(_) => {
                             assert!(true);
                         }
                         _e => {
@@ -233,27 +615,20 @@ mod webdrv_cmds {
                     },
                 }
                 // the following is true on unix, but not win
-                // Err(e) => assert_eq!(
-                //     e,
-                //     BadDrvCmd(
-                //         "script timeout".to_string(),
-                //         "Timed out after 2000 ms".to_string()
-                //     )
-                // ),
+                // Err(BadDrvCmd(e)) => {
+                //     assert_eq!(e.code(), &WebDriverErrorCode::ScriptTimeout)
+                // }
             }
 
             #[test]
             fn exec_async2() {
                 let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
 
-                let eval_ret = wdc
-                    .exec_async(
-                        "var cb = arguments[arguments.length-1]; cb(0xcafe);",
-                        vec![],
-                    )
+                let eval_ret: u32 = wdc
+                    .exec_async("var cb = arguments[arguments.length-1]; cb(0xcafe);", ())
                     .expect("exec_sync");
 
-                assert_eq!(eval_ret, b"51966");
+                assert_eq!(eval_ret, 51966);
             }
         }
 
@@ -269,39 +644,33 @@ mod webdrv_cmds {
                 capa.set_proxy_type("manual");
                 capa.set_socks_version(5);
                 capa.set_socks_proxy("127.0.0.1:1080");
-                capa.add_prefs("network.proxy.socks_remote_dns", "true"); // non-std
+                capa.add_prefs("network.proxy.socks_remote_dns", true); // non-std
 
                 let wdc = wdc::init_singl_ff(REND_HOST, REND_PORT, &capa, 10).expect("init wdc");
 
                 wdc.navi_to("about:config").unwrap();
 
-                let eleid = wdc.find_elem_css("#warningButton").expect("find elem");
-                let eleid = String::from_utf8_lossy(&eleid);
+                let elem = wdc.find_elem_css("#warningButton").expect("find elem");
 
-                wdc.elem_send_keys(&eleid, r"\uE007").expect("send keys");
+                elem.send_keys("\u{E007}").expect("send keys");
 
-                let eleid = wdc.find_elem_css("#about-config-search").unwrap();
-                let eleid = String::from_utf8_lossy(&eleid);
+                let elem = wdc.find_elem_css("#about-config-search").unwrap();
 
-                wdc.elem_send_keys(&eleid, "network.proxy.")
-                    .expect("send keys");
+                elem.send_keys("network.proxy.").expect("send keys");
 
                 // GUI event is slow
                 sleep(Duration::from_millis(100));
 
-                let table_content = wdc
-                    .exec_sync("return document.getElementById('prefs').innerText", vec![])
+                let table_content: String = wdc
+                    .exec_sync("return document.getElementById('prefs').innerText", ())
                     .expect("exec_sync");
 
                 dbg!(&table_content);
 
                 // innerText tricks
-                assert!(String::from_utf8_lossy(&table_content)
-                    .contains(r"network.proxy.socks\t127.0.0.1"));
-                assert!(String::from_utf8_lossy(&table_content)
-                    .contains(r"network.proxy.socks_port\t1080"));
-                assert!(String::from_utf8_lossy(&table_content)
-                    .contains(r"network.proxy.socks_remote_dns\ttrue"));
+                assert!(table_content.contains("network.proxy.socks\t127.0.0.1"));
+                assert!(table_content.contains("network.proxy.socks_port\t1080"));
+                assert!(table_content.contains("network.proxy.socks_remote_dns\ttrue"));
             }
 
             #[test]
@@ -316,6 +685,87 @@ mod webdrv_cmds {
                 assert!(ssmeta.platform_name().is_some());
                 assert!(ssmeta.browser_profile().is_some());
             }
+
+            #[test]
+            fn attach1() {
+                let mut wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("data:text/html,<title>attach1</title>")
+                    .expect("navi to");
+                let ssmeta =
+                    serde_json::to_string(wdc.last_session_meta().expect("buggy")).expect("ser");
+                wdc.set_detach_on_drop(true);
+                drop(wdc);
+
+                let ssmeta: wdc::SessionMeta = serde_json::from_str(&ssmeta).expect("deser");
+                let ssid = ssmeta.session_id().to_string();
+                let wdc =
+                    wdc::WebDrvClient::<RendKind>::attach_with_meta(REND_HOST, REND_PORT, ssmeta)
+                        .expect("attach");
+                assert_eq!(wdc.title().expect("get title"), "attach1");
+                drop(wdc); // the session is deleted this time
+
+                let ret = wdc::WebDrvClient::<RendKind>::attach(REND_HOST, REND_PORT, &ssid);
+                assert!(
+                    matches!(ret, Err(BadDrvCmd(e)) if e.code() == &WebDriverErrorCode::InvalidSessionId)
+                );
+            }
+
+            #[test]
+            fn context1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                assert_eq!(wdc.context().expect("get context"), GeckoContext::Content);
+
+                wdc.set_context(GeckoContext::Chrome).expect("set context");
+                assert_eq!(wdc.context().expect("get context"), GeckoContext::Chrome);
+                // privileged scripts
+                let name: String = wdc
+                    .exec_sync("return Services.appinfo.name;", ())
+                    .expect("exec sync");
+                assert_eq!(name, "Firefox");
+
+                wdc.set_context(GeckoContext::Content).expect("set context");
+                assert_eq!(wdc.context().expect("get context"), GeckoContext::Content);
+            }
+
+            #[test]
+            fn addon1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+
+                let dir = std::env::temp_dir().join("wdc-addon1");
+                std::fs::create_dir_all(&dir).expect("create addon dir");
+                std::fs::write(
+                    dir.join("manifest.json"),
+                    r#"{"manifest_version":2,"name":"wdc","version":"1.0","browser_specific_settings":{"gecko":{"id":"addon1@wdc.test"}}}"#,
+                )
+                .expect("write manifest");
+
+                let id = wdc
+                    .install_addon(dir.to_str().unwrap(), true)
+                    .expect("install addon");
+                assert_eq!(id, "addon1@wdc.test");
+                wdc.uninstall_addon(&id).expect("uninstall addon");
+
+                let ret = wdc.install_addon("/wdc/no/such/addon.xpi", true);
+                assert!(matches!(
+                    ret,
+                    Err(wdc::WdcError::Io(std::io::ErrorKind::NotFound, _))
+                ));
+            }
+
+            #[test]
+            fn screenshot_full1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("data:text/html,<div style='height:5000px'>tall</div>")
+                    .expect("navi to");
+
+                let png = wdc.screenshot_full_to_vec().expect("full screenshot");
+                let (_, height) = png_size(&png).expect("png");
+                assert!(height >= 5000);
+
+                let viewport = wdc.screenshot_to_vec().expect("screenshot");
+                let (_, vheight) = png_size(&viewport).expect("png");
+                assert!(vheight < height);
+            }
         }
     }
 
@@ -327,6 +777,7 @@ mod webdrv_cmds {
     mod chrom {
 
         use super::*;
+        use wdc::wdcmd::goog::NetworkConditions;
         use wdc::wdcmd::session::ChromiumCapa;
         use wdc::ChromeDriver;
 
@@ -348,15 +799,45 @@ mod webdrv_cmds {
                 assert_eq!(cur_url, b"chrome://version/");
             }
 
+            #[test]
+            fn navi_history1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("data:text/html,<title>one</title>")
+                    .expect("navi to")
+                    .navi_to("data:text/html,<title>two</title>")
+                    .expect("navi to");
+                assert_eq!(wdc.title().expect("get title"), "two");
+                assert_eq!(wdc.back().expect("back").title().expect("get title"), "one");
+                assert_eq!(
+                    wdc.forward().expect("forward").title().expect("get title"),
+                    "two"
+                );
+                assert_eq!(
+                    wdc.refresh().expect("refresh").title().expect("get title"),
+                    "two"
+                );
+            }
+
+            #[test]
+            fn timeouts1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                let mut timeouts = wdc.get_timeouts().expect("get timeouts");
+                timeouts.set_implicit(1500).set_script(Some(12_000));
+                wdc.set_timeouts(&timeouts).expect("set timeouts");
+                let actual = wdc.get_timeouts().expect("get timeouts");
+                assert_eq!(actual.implicit(), 1500);
+                assert_eq!(actual.script(), Some(12_000));
+                assert_eq!(actual.page_load(), timeouts.page_load());
+            }
+
             #[test]
             fn find_elem1() {
                 let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
                 wdc.navi_to("chrome://version").expect("navi to");
-                let eleid = wdc.find_elem_css("#outer").expect("find elem");
-                let eleid = String::from_utf8_lossy(&eleid);
-                assert!(eleid.len() >= 32);
-                dbg!(&eleid);
-                // assert!(is_uuid(&eleid)); // chrome@fedora/macos fail
+                let elem = wdc.find_elem_css("#outer").expect("find elem");
+                assert!(elem.id().len() >= 32);
+                dbg!(&elem);
+                // assert!(is_uuid(elem.id())); // chrome@fedora/macos fail
             }
 
             #[test]
@@ -365,36 +846,279 @@ mod webdrv_cmds {
                 wdc.navi_to("chrome://version").expect("navi to");
                 let eleids = wdc.find_elems_css("#outer").expect("find elem");
                 assert!(eleids.len() > 0);
-                for id in eleids {
-                    dbg!(&id);
-                    assert!(id.len() >= 32);
-                    // assert!(is_uuid(&id)); // chrome@fedora/macos fail
+                for elem in eleids {
+                    dbg!(&elem);
+                    assert!(elem.id().len() >= 32);
+                    // assert!(is_uuid(elem.id())); // chrome@fedora/macos fail
                 }
             }
 
             #[test]
-            fn screenshot1() {
+            fn find_elem3() {
                 let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
                 wdc.navi_to("chrome://version").expect("navi to");
-                wdc.screenshot(&pbody_file(PBODY_FILENAME_PREFIX, "sshot"))
-                    .expect("screenshot");
-                // check b64 data
-                let mut buf = [0u8; 4096];
-                let mut b64png = OpenOptions::new()
-                    .read(true)
-                    .open(&pbody_file(PBODY_FILENAME_PREFIX, "sshot"))
-                    .expect("pbody file");
-                loop {
-                    let nread = b64png.read(&mut buf).unwrap();
-                    if nread == 0 {
+                let eleid1 = wdc.find_elem_css("#outer").expect("find elem");
+                let eleid2 = wdc
+                    .find_elem(Locator::XPath("//*[@id='outer']"))
+                    .expect("find elem");
+                assert_eq!(eleid1, eleid2);
+                let eleids = wdc.find_elems(Locator::TagName("div")).expect("find elems");
+                assert!(!eleids.is_empty());
+                assert!(matches!(
+                    wdc.find_elem(Locator::LinkText("no such link")),
+                    Err(BadDrvCmd(err)) if err.code() == &WebDriverErrorCode::NoSuchElement
+                ));
+            }
+
+            #[test]
+            fn find_elem_from_elem1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to(
+                    "data:text/html,<ul id='a'><li>1</li><li>2</li></ul><ul id='b'><li>3</li></ul>",
+                )
+                .expect("navi to");
+                let parent = wdc.find_elem_css("#a").expect("find elem");
+                let elem = parent
+                    .find_elem(Locator::Css("li"))
+                    .expect("find elem from elem");
+                assert_eq!(elem.tag_name().expect("get tag name"), "li");
+                let elems = wdc
+                    .find_elems_from_elem(parent.id(), Locator::Css("li"))
+                    .expect("find elems from elem");
+                assert_eq!(elems.len(), 2);
+                assert_eq!(elems[0], elem);
+                assert_eq!(elems[1].text().expect("get text"), "2");
+            }
+
+            #[test]
+            fn find_elem_from_shadow1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("data:text/html,<div id='host'></div>")
+                    .expect("navi to");
+                wdc.exec_sync::<()>(
+                    "document.getElementById('host').attachShadow({mode:'open'}).innerHTML='<p>1</p><p>2</p>';",
+                    (),
+                )
+                .expect("exec sync");
+                let host = wdc.find_elem_css("#host").expect("find elem");
+                let shadowid = host.shadow_root().expect("get shadow root");
+                let elem = wdc
+                    .find_elem_from_shadow(&shadowid, Locator::Css("p"))
+                    .expect("find elem from shadow");
+                assert_eq!(elem.text().expect("get text"), "1");
+                let eleids = wdc
+                    .find_elems_from_shadow(&shadowid, Locator::Css("p"))
+                    .expect("find elems from shadow");
+                assert_eq!(eleids.len(), 2);
+                assert!(wdc.find_elem_css("p").is_err());
+            }
+
+            #[test]
+            fn elem_state1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("chrome://version").expect("navi to");
+                let elem = wdc.find_elem_css("#outer").expect("find elem");
+                assert_eq!(
+                    elem.attr("id").expect("get attr"),
+                    Some("outer".to_string())
+                );
+                assert_eq!(elem.attr("nonexist").expect("get attr"), None);
+                assert!(!elem.text().expect("get text").is_empty());
+                assert!(!elem.tag_name().expect("get tag name").is_empty());
+                assert!(elem.rect().expect("get rect").width() > 0.0);
+                assert!(elem.is_displayed().expect("is displayed"));
+                assert!(elem.is_enabled().expect("is enabled"));
+            }
+
+            #[test]
+            fn elem_interact1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to(
+                    "data:text/html,<input id='t' value='abc'><input id='c' type='checkbox'>",
+                )
+                .expect("navi to");
+                let elem = wdc.find_elem_css("#t").expect("find elem");
+                assert_eq!(elem.prop("value").expect("get prop"), "abc");
+                elem.clear().expect("clear");
+                assert_eq!(elem.prop("value").expect("get prop"), "");
+                assert_eq!(elem.tag_name().expect("get tag name"), "input");
+                assert_eq!(elem.css_value("display").expect("get css"), "inline-block");
+
+                let elem = wdc.find_elem_css("#c").expect("find elem");
+                assert!(!elem.is_selected().expect("is selected"));
+                elem.click().expect("click");
+                assert!(elem.is_selected().expect("is selected"));
+            }
+
+            #[test]
+            fn window1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                let orig = wdc.window_handle().expect("get window handle");
+                let newone = wdc.new_window(WindowType::Tab).expect("new window");
+                let handles = wdc.window_handles().expect("get window handles");
+                assert_eq!(handles.len(), 2);
+                assert!(handles.contains(&orig));
+                assert!(handles.contains(&newone));
+                wdc.switch_to_window(&newone).expect("switch to window");
+                assert_eq!(wdc.window_handle().expect("get window handle"), newone);
+                let remains = wdc.close_window().expect("close window");
+                assert_eq!(remains, vec![orig.clone()]);
+                wdc.switch_to_window(&orig).expect("switch to window");
+            }
+
+            #[test]
+            fn window_rect1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                let ssmeta = wdc.last_session_meta().expect("session meta");
+                assert_eq!(ssmeta.window_rect(), Some(true));
+                let rect = wdc
+                    .set_window_rect(&WindowRect::new(10, 20, 800, 600))
+                    .expect("set window rect");
+                assert_eq!(rect.width(), 800);
+                assert_eq!(rect.height(), 600);
+                assert_eq!(wdc.window_rect().expect("get window rect"), rect);
+                let rect = wdc.maximize_window().expect("maximize window");
+                assert!(rect.width() >= 800);
+            }
+
+            #[test]
+            fn frame1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("data:text/html,<iframe srcdoc='<p id=x>in</p>'></iframe>")
+                    .expect("navi to");
+                assert!(wdc.find_elem_css("#x").is_err());
+                let frame = wdc.find_elem_css("iframe").expect("find elem");
+                let text = wdc
+                    .within_frame(FrameId::Elem(frame.id()), |wdc| {
+                        wdc.find_elem_css("#x")?.text()
+                    })
+                    .expect("within frame");
+                assert_eq!(text, "in");
+                assert!(wdc.find_elem_css("#x").is_err());
+                wdc.switch_to_frame(FrameId::Index(0))
+                    .expect("switch to frame");
+                assert!(wdc.find_elem_css("#x").is_ok());
+                wdc.switch_to_frame(FrameId::Top).expect("switch to frame");
+                assert!(wdc.find_elem_css("#x").is_err());
+            }
+
+            #[test]
+            fn frame2() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to(
+                    r#"data:text/html,<iframe srcdoc="<p id=x>out</p><iframe srcdoc='<p id=y>in</p>'></iframe>"></iframe>"#,
+                )
+                .expect("navi to");
+                let outer = wdc.find_elem_css("iframe").expect("find elem");
+                let text = wdc
+                    .within_frame(FrameId::Elem(outer.id()), |wdc| {
+                        let inner = wdc.find_elem_css("iframe")?;
+                        let text = wdc.within_frame(FrameId::Elem(inner.id()), |wdc| {
+                            wdc.find_elem_css("#y")?.text()
+                        })?;
+                        assert_eq!(wdc.find_elem_css("#x")?.text()?, "out");
+
+                        let ret = wdc.within_frame(FrameId::Top, |_| Ok(()));
+                        assert!(matches!(ret, Err(InvalidArgument(_))));
+                        assert!(wdc.find_elem_css("#x").is_ok());
+
+                        Ok(text)
+                    })
+                    .expect("within frame");
+                assert_eq!(text, "in");
+                assert!(wdc.find_elem_css("#x").is_err());
+            }
+
+            #[test]
+            fn cookie1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to(&local_page("<p>cookie</p>")).expect("navi to");
+                wdc.del_all_cookies().expect("del all cookies");
+                assert!(wdc.all_cookies().expect("get all cookies").is_empty());
+
+                let mut cookie = Cookie::new("foo", "bar");
+                cookie.set_path("/").set_http_only(true);
+                wdc.add_cookie(&cookie).expect("add cookie");
+                wdc.add_cookie(&Cookie::new("baz", "qux"))
+                    .expect("add cookie");
+                assert_eq!(wdc.all_cookies().expect("get all cookies").len(), 2);
+
+                let got = wdc.named_cookie("foo").expect("get named cookie");
+                assert_eq!(got.value(), "bar");
+                assert_eq!(got.path(), Some("/"));
+                assert_eq!(got.http_only(), Some(true));
+
+                wdc.del_cookie("foo").expect("del cookie");
+                assert!(matches!(
+                    wdc.named_cookie("foo"),
+                    Err(BadDrvCmd(err)) if err.code() == &WebDriverErrorCode::NoSuchCookie
+                ));
+                wdc.del_all_cookies().expect("del all cookies");
+                assert!(wdc.all_cookies().expect("get all cookies").is_empty());
+            }
+
+            #[test]
+            fn alert1() {
+                let mut capa = ChromiumCapa::default();
+                capa.set_unhandled_prompt_behavior("ignore");
+                let wdc =
+                    wdc::init_singl::<RendKind>(REND_HOST, REND_PORT, &capa, 10).expect("init wdc");
+                let ssmeta = wdc.last_session_meta().expect("session meta");
+                assert_eq!(ssmeta.unhandled_prompt_behavior(), Some("ignore"));
+
+                wdc.navi_to("data:text/html,<p>alert</p>").expect("navi to");
+                wdc.exec_sync::<()>(
+                    "window.setTimeout(function() { window.result = window.prompt('hi'); }, 0);",
+                    (),
+                )
+                .expect("exec sync");
+                // prompt is opened asynchronously
+                let mut text = wdc.alert_text();
+                for _ in 0..50 {
+                    if text.is_ok() {
                         break;
                     }
-                    for ele in &buf[0..nread] {
-                        if *ele == b'}' || *ele == b'}' || *ele == b'"' {
-                            assert!(false, "invalid base64 data");
-                        }
+                    sleep(Duration::from_millis(100));
+                    text = wdc.alert_text();
+                }
+                assert_eq!(text.expect("get alert text"), Some("hi".to_string()));
+                wdc.send_alert_text("there").expect("send alert text");
+                wdc.accept_alert().expect("accept alert");
+                let result: String = wdc
+                    .exec_sync("return window.result;", ())
+                    .expect("exec sync");
+                assert_eq!(result, "there");
+
+                wdc.exec_sync::<()>(
+                    "window.setTimeout(function() { window.alert('bye'); }, 0);",
+                    (),
+                )
+                .expect("exec sync");
+                let mut text = wdc.alert_text();
+                for _ in 0..50 {
+                    if text.is_ok() {
+                        break;
                     }
+                    sleep(Duration::from_millis(100));
+                    text = wdc.alert_text();
                 }
+                assert_eq!(text.expect("get alert text"), Some("bye".to_string()));
+                wdc.dismiss_alert().expect("dismiss alert");
+                assert!(matches!(
+                    wdc.alert_text(),
+                    Err(BadDrvCmd(err)) if err.code() == &WebDriverErrorCode::NoSuchAlert
+                ));
+            }
+
+            #[test]
+            fn screenshot1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("chrome://version").expect("navi to");
+                wdc.screenshot(&pbody_file(PBODY_FILENAME_PREFIX, "sshot"))
+                    .expect("screenshot");
+                let data =
+                    std::fs::read(pbody_file(PBODY_FILENAME_PREFIX, "sshot")).expect("pbody file");
+                assert!(png_size(&data).is_some());
             }
 
             #[test]
@@ -402,53 +1126,66 @@ mod webdrv_cmds {
                 let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
                 wdc.navi_to("chrome://version").expect("navi to");
 
-                let eleid = wdc.find_elem_css("#outer").expect("find elem");
-                let eleid = String::from_utf8_lossy(&eleid);
-                wdc.screenshot_elem(&eleid, &pbody_file(PBODY_FILENAME_PREFIX, "sshot-elem"))
+                let elem = wdc.find_elem_css("#outer").expect("find elem");
+                elem.screenshot(&pbody_file(PBODY_FILENAME_PREFIX, "sshot-elem"))
                     .expect("screenshot elem");
 
-                // check b64 data
-                let mut buf = [0u8; 4096];
-                let mut b64png = OpenOptions::new()
-                    .read(true)
-                    .open(&pbody_file(PBODY_FILENAME_PREFIX, "sshot-elem"))
+                let data = std::fs::read(pbody_file(PBODY_FILENAME_PREFIX, "sshot-elem"))
                     .expect("pbody file");
-                loop {
-                    let nread = b64png.read(&mut buf).unwrap();
-                    if nread == 0 {
-                        break;
-                    }
-                    for ele in &buf[0..nread] {
-                        if *ele == b'}' || *ele == b'}' || *ele == b'"' {
-                            assert!(false, "invalid base64 data");
-                        }
-                    }
-                }
+                assert!(png_size(&data).is_some());
+            }
+
+            #[test]
+            fn screenshot2() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("data:text/html,<div id='box' style='width:40px;height:30px'></div>")
+                    .expect("navi to");
+                let png = wdc.screenshot_to_vec().expect("screenshot failed");
+                let (width, height) = png_size(&png).expect("png size");
+                assert!(width > 0 && height > 0);
+
+                let elem = wdc.find_elem(Locator::Css("#box")).expect("find elem");
+                let png = elem.screenshot_to_vec().expect("screenshot failed");
+                let (ewidth, eheight) = png_size(&png).expect("png size");
+                assert!((40..=width).contains(&ewidth));
+                assert!((30..=height).contains(&eheight));
+
+                let pdf = wdc
+                    .print_page_to_vec(&PrintOptions::default())
+                    .expect("print page");
+                assert!(pdf.starts_with(b"%PDF-"));
+            }
+
+            #[test]
+            fn print_page2() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("about:blank").expect("navi to");
+                let mut opts = PrintOptions::default();
+                opts.set_orientation(Orientation::Landscape)
+                    .set_page_size(21.0, 29.7)
+                    .set_margin(0.5, 0.5, 1.0, 1.0)
+                    .set_scale(0.8)
+                    .set_shrink_to_fit(false)
+                    .add_page_range(PageRange::Page(1));
+                wdc.print_page(&opts, &pbody_file(PBODY_FILENAME_PREFIX, "ppdf2"))
+                    .expect("print page");
+                let data =
+                    std::fs::read(pbody_file(PBODY_FILENAME_PREFIX, "ppdf2")).expect("pbody file");
+                assert!(data.starts_with(b"%PDF-"));
             }
 
             #[test]
             fn print_page1() {
                 let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
                 wdc.navi_to("chrome://version").expect("navi to");
-                wdc.print_page(&pbody_file(PBODY_FILENAME_PREFIX, "ppdf"))
-                    .expect("print page");
-                // check b64 data
-                let mut buf = [0u8; 4096];
-                let mut b64png = OpenOptions::new()
-                    .read(true)
-                    .open(&pbody_file(PBODY_FILENAME_PREFIX, "ppdf"))
-                    .expect("pbody file");
-                loop {
-                    let nread = b64png.read(&mut buf).unwrap();
-                    if nread == 0 {
-                        break;
-                    }
-                    for ele in &buf[0..nread] {
-                        if *ele == b'}' || *ele == b'}' || *ele == b'"' {
-                            assert!(false, "invalid base64 data");
-                        }
-                    }
-                }
+                wdc.print_page(
+                    &PrintOptions::default(),
+                    &pbody_file(PBODY_FILENAME_PREFIX, "ppdf"),
+                )
+                .expect("print page");
+                let data =
+                    std::fs::read(pbody_file(PBODY_FILENAME_PREFIX, "ppdf")).expect("pbody file");
+                assert!(data.starts_with(b"%PDF-"));
             }
 
             #[test]
@@ -468,10 +1205,8 @@ mod webdrv_cmds {
                     std::fs::read_to_string(&pbody_file(PBODY_FILENAME_PREFIX, "pagesrc"))
                         .expect("pbody file");
 
-                // FIXTHEM: the resulting html is corrupted with octal
-                assert!(!data_got.contains("<html"));
-                assert!(data_got.contains("html"));
-                assert!(data_got.contains("body"));
+                assert!(data_got.contains("<html"));
+                assert!(data_got.contains("<body"));
             }
 
             #[test]
@@ -490,28 +1225,78 @@ mod webdrv_cmds {
                 assert!(retstr.contains("body"));
             }
 
+            #[test]
+            fn page_src3() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+
+                wdc.navi_to("data:text/html,<p>hi</p>").expect("navi to");
+
+                let mut sink = std::io::BufWriter::new(Vec::<u8>::new());
+                wdc.page_src_to_writer(&mut sink).expect("page src");
+                let got = sink.into_inner().expect("flush");
+                assert!(String::from_utf8_lossy(&got).contains("<p>hi</p>"));
+
+                let mut sink = std::io::Cursor::new(Vec::<u8>::new());
+                wdc.screenshot_to_writer(&mut sink).expect("screenshot");
+                assert!(png_size(sink.get_ref()).is_some());
+
+                let mut sink = Vec::<u8>::new();
+                wdc.print_page_to_writer(&PrintOptions::default(), &mut sink)
+                    .expect("print page");
+                assert!(sink.starts_with(b"%PDF-"));
+            }
+
+            #[test]
+            fn page_src4() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+
+                let text = "\"quoted\" 'single' a\\b\ncaf\u{e9} \u{65e5}\u{672c} \u{1f600}";
+                wdc.navi_to("data:text/html,<p>hi</p>").expect("navi to");
+                wdc.exec_sync::<()>(
+                    "document.querySelector('p').textContent = arguments[0];",
+                    [text],
+                )
+                .expect("exec sync");
+                let expected = format!("<p>{}</p>", text);
+
+                let got = wdc.page_src(None).expect("page src").expect("in memory");
+                assert!(got
+                    .windows(expected.len())
+                    .any(|w| w == expected.as_bytes()));
+
+                let mut got = Vec::<u8>::new();
+                wdc.page_src_to_writer(&mut got).expect("page src");
+                assert!(got
+                    .windows(expected.len())
+                    .any(|w| w == expected.as_bytes()));
+            }
+
             #[test]
             fn exec_sync1() {
                 let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
 
-                let eval_ret = wdc.exec_sync("return 0xcafe", vec![]).expect("exec_sync");
-                assert_eq!(eval_ret, b"51966");
+                let eval_ret: u32 = wdc.exec_sync("return 0xcafe", ()).expect("exec_sync");
+                assert_eq!(eval_ret, 51966);
+
+                let eval_ret: String = wdc.exec_sync("return '0xcafe'", ()).expect("exec_sync");
+                assert_eq!(eval_ret, "0xcafe");
 
-                let eval_ret = wdc.exec_sync("return '0xcafe'", vec![]).expect("exec_sync");
-                assert_eq!(eval_ret, br#""0xcafe""#);
+                // a mismatched type is not a driver error
+                let eval_ret = wdc.exec_sync::<u32>("return '0xcafe'", ());
+                assert!(matches!(eval_ret, Err(UnexpectedScriptResult(_))));
             }
 
             #[test]
             fn exec_sync2() {
                 let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
 
-                let eval_ret = wdc.exec_sync("throw 123456789", vec![]);
+                let eval_ret = wdc.exec_sync::<()>("throw 123456789", ());
                 assert_eq!(eval_ret.is_ok(), false);
 
                 if let Err(eobj) = eval_ret {
-                    if let BadDrvCmd(err, msg) = eobj {
-                        assert_eq!(err, "javascript error");
-                        assert_eq!(msg.contains("javascript error"), true);
+                    if let BadDrvCmd(e) = eobj {
+                        assert_eq!(e.code(), &WebDriverErrorCode::JavascriptError);
+                        assert_eq!(e.message().contains("javascript error"), true);
                     } else {
                         assert!(false);
                     }
@@ -524,18 +1309,57 @@ mod webdrv_cmds {
             fn exec_sync3() {
                 let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
 
-                let eval_ret = wdc
-                    .exec_sync("return arguments[0] + arguments[1];", vec!["3", "4"])
+                let eval_ret: i32 = wdc
+                    .exec_sync("return arguments[0] + arguments[1];", (3, 4))
                     .expect("exec_sync");
-                assert_eq!(eval_ret, b"7");
+                assert_eq!(eval_ret, 7);
 
-                let eval_ret = wdc
-                    .exec_sync(
-                        "return arguments[0] + arguments[1];",
-                        vec!["\"3\"", "\"4\""],
-                    )
+                let eval_ret: String = wdc
+                    .exec_sync("return arguments[0] + arguments[1];", ["3", "4"])
+                    .expect("exec_sync");
+                assert_eq!(eval_ret, "34");
+
+                let eval_ret: Vec<String> = wdc
+                    .exec_sync("return arguments[0].split(',');", vec!["a,b"])
+                    .expect("exec_sync");
+                assert_eq!(eval_ret, vec!["a", "b"]);
+            }
+
+            #[test]
+            fn exec_sync4() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+
+                // quotes, backslashes and newlines are sent as they are
+                let eval_ret: String = wdc
+                    .exec_sync("return \"a\\\\b\" +\n'\"c\"' + arguments[0];", ["\"d\""])
                     .expect("exec_sync");
-                assert_eq!(eval_ret, b"\"34\"");
+                assert_eq!(eval_ret, r#"a\b"c""d""#);
+
+                // arguments must be a sequence
+                let eval_ret = wdc.exec_sync::<()>("return 1;", "x");
+                assert!(matches!(eval_ret, Err(InvalidArgument(_))));
+            }
+
+            #[test]
+            fn exec_sync5() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("data:text/html,<p id='a'>1</p><p id='b'>2</p>")
+                    .expect("navi to");
+
+                // elements as arguments
+                let elem = wdc.find_elem_css("#b").expect("find elem");
+                let text: String = wdc
+                    .exec_sync("return arguments[0].innerText;", [&elem])
+                    .expect("exec_sync");
+                assert_eq!(text, "2");
+
+                // elements as results
+                let elems: Vec<ElemRef> = wdc
+                    .exec_sync("return document.querySelectorAll('p');", ())
+                    .expect("exec_sync");
+                assert_eq!(elems.len(), 2);
+                assert_eq!(wdc.elem_from_ref(&elems[1]), elem);
+                assert_eq!(wdc.elem_from_ref(&elems[0]).text().expect("text"), "1");
             }
 
             #[test]
@@ -546,13 +1370,13 @@ mod webdrv_cmds {
                 let wdc =
                     wdc::init_singl::<RendKind>(REND_HOST, REND_PORT, &capa, 10).expect("init wdc");
 
-                let eval_ret = wdc.exec_async("console.log(123)", vec![]);
+                let eval_ret = wdc.exec_async::<()>("console.log(123)", ());
 
                 match eval_ret {
                     Ok(_) => assert!(false),
                     Err(e) => match e {
-                        BadDrvCmd(cmderr, _cmdemsg) => {
-                            assert_eq!(cmderr, "script timeout");
+                        BadDrvCmd(e) => {
+                            assert_eq!(e.code(), &WebDriverErrorCode::ScriptTimeout);
                         }
                         _ee => {
                             dbg!(_ee);
@@ -566,14 +1390,11 @@ mod webdrv_cmds {
             fn exec_async2() {
                 let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
 
-                let eval_ret = wdc
-                    .exec_async(
-                        "var cb = arguments[arguments.length-1]; cb(0xcafe);",
-                        vec![],
-                    )
+                let eval_ret: u32 = wdc
+                    .exec_async("var cb = arguments[arguments.length-1]; cb(0xcafe);", ())
                     .expect("exec_sync");
 
-                assert_eq!(eval_ret, b"51966");
+                assert_eq!(eval_ret, 51966);
             }
 
             #[test]
@@ -648,6 +1469,29 @@ mod webdrv_cmds {
 
                 let _eval_ret = wdc.perform_actions(actg).expect("perform actions");
             }
+
+            #[test]
+            fn release_actions1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+
+                let mut actg = ActionGroup::default();
+                actg.add_key_act("dev1").add_subact().keydown().left_shift();
+                wdc.perform_actions(actg).expect("perform actions");
+
+                let mut actg = ActionGroup::default();
+                actg.add_pointer_act("dev1").add_subact().ptr_down();
+                assert!(matches!(
+                    wdc.perform_actions(actg),
+                    Err(wdc::WdcError::InputSourceTypeMismatch(id)) if id == "dev1"
+                ));
+
+                wdc.release_actions().expect("release actions");
+
+                let mut actg = ActionGroup::default();
+                actg.add_pointer_act("dev1").add_subact().ptr_down();
+                wdc.perform_actions(actg).expect("perform actions");
+                wdc.release_actions().expect("release actions");
+            }
         }
 
         mod non_w3c {
@@ -667,6 +1511,105 @@ mod webdrv_cmds {
                 assert!(ssmeta.platform_name().is_some());
                 assert!(ssmeta.browser_profile().is_none());
             }
+
+            #[test]
+            fn sessions1() {
+                let mut wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                wdc.navi_to("data:text/html,<title>one</title>")
+                    .expect("navi to");
+
+                let ssid = {
+                    let ss = wdc
+                        .new_session(&ChromiumCapa::default())
+                        .expect("new session");
+                    ss.navi_to("data:text/html,<title>two</title>")
+                        .expect("navi to");
+                    assert_eq!(ss.title().expect("get title"), "two");
+                    ss.session_id().to_string()
+                };
+                assert_eq!(wdc.session_metas().len(), 2);
+                assert_eq!(wdc.title().expect("get title"), "one");
+
+                let ss = wdc.session(&ssid).expect("pick session");
+                assert_eq!(ss.title().expect("get title"), "two");
+                drop(ss);
+
+                wdc.end_session(&ssid).expect("end session");
+                assert_eq!(wdc.session_metas().len(), 1);
+                assert!(matches!(
+                    wdc.session(&ssid),
+                    Err(wdc::WdcError::UnknownSession(_))
+                ));
+                assert_eq!(wdc.title().expect("get title"), "one");
+            }
+
+            #[test]
+            fn execute_cdp1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+
+                let res: serde_json::Value = wdc
+                    .execute_cdp(
+                        "Runtime.evaluate",
+                        serde_json::json!({"expression": "1 + 1"}),
+                    )
+                    .expect("execute cdp");
+                assert_eq!(res["result"]["value"], 2);
+
+                let res: serde_json::Value = wdc
+                    .execute_cdp("Browser.getVersion", ())
+                    .expect("execute cdp");
+                assert!(res["product"].as_str().is_some());
+
+                let ret = wdc.execute_cdp::<()>("Runtime.evaluate", ["1 + 1"]);
+                assert!(matches!(ret, Err(InvalidArgument(_))));
+
+                let ret = wdc.execute_cdp::<u32>("Browser.getVersion", ());
+                assert!(matches!(ret, Err(wdc::WdcError::UnexpectedCdpResult(_))));
+            }
+
+            #[test]
+            fn network_conditions1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+
+                let mut conds = NetworkConditions::new(20.0, 100.0 * 1024.0, 50.0 * 1024.0);
+                wdc.set_network_conditions(&conds)
+                    .expect("set network conditions");
+                assert_eq!(
+                    wdc.network_conditions().expect("get network conditions"),
+                    conds
+                );
+
+                conds.set_offline(true);
+                wdc.set_network_conditions(&conds)
+                    .expect("set network conditions");
+                assert!(wdc
+                    .network_conditions()
+                    .expect("get network conditions")
+                    .offline());
+
+                wdc.delete_network_conditions()
+                    .expect("delete network conditions");
+                assert!(wdc.network_conditions().is_err());
+            }
+
+            #[test]
+            fn log1() {
+                let wdc = wdc::init::<RendKind>(REND_HOST, REND_PORT, 10).expect("init wdc");
+                assert!(wdc
+                    .log_types()
+                    .expect("get log types")
+                    .contains(&"browser".to_string()));
+
+                wdc.exec_sync::<()>("console.error('wdc-log1');", ())
+                    .expect("exec sync");
+                let entries = wdc.log("browser").expect("get log");
+                assert!(entries
+                    .iter()
+                    .any(|e| e.level() == "SEVERE" && e.message().contains("wdc-log1")));
+                // entries are taken away once retrieved
+                let entries = wdc.log("browser").expect("get log");
+                assert!(entries.iter().all(|e| !e.message().contains("wdc-log1")));
+            }
         }
     }
 
@@ -696,4 +1639,29 @@ mod webdrv_cmds {
     fn pbody_file(prefix: &str, req: &str) -> String {
         format!("wdctmp/{}-{}.pbody", prefix, req)
     }
+
+    // serve `html` over plain http in background, return the page url
+    #[allow(unused)]
+    fn local_page(html: &'static str) -> String {
+        use std::io::Write;
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut rbuf = [0u8; 4096];
+                let _ = stream.read(&mut rbuf);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    html.len(),
+                    html
+                );
+            }
+        });
+
+        url
+    }
 }